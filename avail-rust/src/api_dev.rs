@@ -16255,6 +16255,108 @@ pub mod api {
 					const PALLET: &'static str = "DataAvailability";
 					const CALL: &'static str = "set_submit_data_fee_modifier";
 				}
+				#[derive(
+					:: subxt :: ext :: subxt_core :: ext :: codec :: Decode,
+					:: subxt :: ext :: subxt_core :: ext :: codec :: Encode,
+					:: subxt :: ext :: subxt_core :: ext :: scale_decode :: DecodeAsType,
+					:: subxt :: ext :: subxt_core :: ext :: scale_encode :: EncodeAsType,
+					Clone,
+					Debug,
+					Eq,
+					PartialEq,
+				)]
+				# [codec (crate = :: subxt :: ext :: subxt_core :: ext :: codec)]
+				#[codec(dumb_trait_bound)]
+				#[decode_as_type(
+					crate_path = ":: subxt :: ext :: subxt_core :: ext :: scale_decode"
+				)]
+				#[encode_as_type(
+					crate_path = ":: subxt :: ext :: subxt_core :: ext :: scale_encode"
+				)]
+				#[doc = "See [`Pallet::submit_data_with_options`]."]
+				pub struct SubmitDataWithOptions {
+					pub data: submit_data_with_options::Data,
+					pub codec: submit_data_with_options::Codec,
+					pub namespace: submit_data_with_options::Namespace,
+				}
+				pub mod submit_data_with_options {
+					use super::runtime_types;
+					pub type Data = runtime_types::bounded_collections::bounded_vec::BoundedVec<
+						::core::primitive::u8,
+					>;
+					pub type Codec = runtime_types::da_control::pallet::CompressionCodec;
+					pub type Namespace = ::core::option::Option<::core::primitive::u32>;
+				}
+				impl ::subxt::ext::subxt_core::blocks::StaticExtrinsic for SubmitDataWithOptions {
+					const PALLET: &'static str = "DataAvailability";
+					const CALL: &'static str = "submit_data_with_options";
+				}
+				#[derive(
+					:: subxt :: ext :: subxt_core :: ext :: codec :: Decode,
+					:: subxt :: ext :: subxt_core :: ext :: codec :: Encode,
+					:: subxt :: ext :: subxt_core :: ext :: scale_decode :: DecodeAsType,
+					:: subxt :: ext :: subxt_core :: ext :: scale_encode :: EncodeAsType,
+					Clone,
+					Debug,
+					Eq,
+					PartialEq,
+				)]
+				# [codec (crate = :: subxt :: ext :: subxt_core :: ext :: codec)]
+				#[codec(dumb_trait_bound)]
+				#[decode_as_type(
+					crate_path = ":: subxt :: ext :: subxt_core :: ext :: scale_decode"
+				)]
+				#[encode_as_type(
+					crate_path = ":: subxt :: ext :: subxt_core :: ext :: scale_encode"
+				)]
+				#[doc = "See [`Pallet::schedule_block_length_proposal`]."]
+				pub struct ScheduleBlockLengthProposal {
+					pub rows: schedule_block_length_proposal::Rows,
+					pub cols: schedule_block_length_proposal::Cols,
+					pub enact_at: schedule_block_length_proposal::EnactAt,
+				}
+				pub mod schedule_block_length_proposal {
+					use super::runtime_types;
+					pub type Rows = ::core::primitive::u32;
+					pub type Cols = ::core::primitive::u32;
+					pub type EnactAt = ::core::primitive::u32;
+				}
+				impl ::subxt::ext::subxt_core::blocks::StaticExtrinsic for ScheduleBlockLengthProposal {
+					const PALLET: &'static str = "DataAvailability";
+					const CALL: &'static str = "schedule_block_length_proposal";
+				}
+				#[derive(
+					:: subxt :: ext :: subxt_core :: ext :: codec :: Decode,
+					:: subxt :: ext :: subxt_core :: ext :: codec :: Encode,
+					:: subxt :: ext :: subxt_core :: ext :: scale_decode :: DecodeAsType,
+					:: subxt :: ext :: subxt_core :: ext :: scale_encode :: EncodeAsType,
+					Clone,
+					Debug,
+					Eq,
+					PartialEq,
+				)]
+				# [codec (crate = :: subxt :: ext :: subxt_core :: ext :: codec)]
+				#[codec(dumb_trait_bound)]
+				#[decode_as_type(
+					crate_path = ":: subxt :: ext :: subxt_core :: ext :: scale_decode"
+				)]
+				#[encode_as_type(
+					crate_path = ":: subxt :: ext :: subxt_core :: ext :: scale_encode"
+				)]
+				#[doc = "See [`Pallet::set_block_length_auto_scaling`]."]
+				pub struct SetBlockLengthAutoScaling {
+					pub params: set_block_length_auto_scaling::Params,
+				}
+				pub mod set_block_length_auto_scaling {
+					use super::runtime_types;
+					pub type Params = ::core::option::Option<
+						runtime_types::da_control::pallet::BlockLengthAutoScaling,
+					>;
+				}
+				impl ::subxt::ext::subxt_core::blocks::StaticExtrinsic for SetBlockLengthAutoScaling {
+					const PALLET: &'static str = "DataAvailability";
+					const CALL: &'static str = "set_block_length_auto_scaling";
+				}
 			}
 			pub struct TransactionApi;
 			impl TransactionApi {
@@ -16349,6 +16451,25 @@ pub mod api {
 						],
 					)
 				}
+				#[doc = "See [`Pallet::submit_data_with_options`]."]
+				pub fn submit_data_with_options(
+					&self,
+					data: types::submit_data_with_options::Data,
+					codec: types::submit_data_with_options::Codec,
+					namespace: types::submit_data_with_options::Namespace,
+				) -> ::subxt::ext::subxt_core::tx::payload::StaticPayload<
+					types::SubmitDataWithOptions,
+				> {
+					::subxt::ext::subxt_core::tx::payload::StaticPayload::new(
+						"DataAvailability",
+						"submit_data_with_options",
+						types::SubmitDataWithOptions {
+							data,
+							codec,
+							namespace,
+						},
+					)
+				}
 			}
 		}
 		#[doc = "Event for the pallet."]
@@ -16404,11 +16525,15 @@ pub mod api {
 			pub struct DataSubmitted {
 				pub who: data_submitted::Who,
 				pub data_hash: data_submitted::DataHash,
+				pub codec: data_submitted::Codec,
+				pub namespace: data_submitted::Namespace,
 			}
 			pub mod data_submitted {
 				use super::runtime_types;
 				pub type Who = ::subxt::ext::subxt_core::utils::AccountId32;
 				pub type DataHash = ::subxt::ext::subxt_core::utils::H256;
+				pub type Codec = runtime_types::da_control::pallet::CompressionCodec;
+				pub type Namespace = ::core::option::Option<::core::primitive::u32>;
 			}
 			impl ::subxt::ext::subxt_core::events::StaticEvent for DataSubmitted {
 				const PALLET: &'static str = "DataAvailability";
@@ -28090,6 +28215,78 @@ pub mod api {
 					set_submit_data_fee_modifier {
 						modifier: runtime_types::frame_support::dispatch::DispatchFeeModifier,
 					},
+					#[codec(index = 13)]
+					#[doc = "See [`Pallet::submit_data_with_options`]."]
+					submit_data_with_options {
+						data: runtime_types::bounded_collections::bounded_vec::BoundedVec<
+							::core::primitive::u8,
+						>,
+						codec: runtime_types::da_control::pallet::CompressionCodec,
+						namespace: ::core::option::Option<::core::primitive::u32>,
+					},
+					#[codec(index = 15)]
+					#[doc = "See [`Pallet::schedule_block_length_proposal`]."]
+					schedule_block_length_proposal {
+						rows: ::core::primitive::u32,
+						cols: ::core::primitive::u32,
+						enact_at: ::core::primitive::u32,
+					},
+					#[codec(index = 16)]
+					#[doc = "See [`Pallet::set_block_length_auto_scaling`]."]
+					set_block_length_auto_scaling {
+						params: ::core::option::Option<
+							runtime_types::da_control::pallet::BlockLengthAutoScaling,
+						>,
+					},
+				}
+				#[derive(
+					:: subxt :: ext :: subxt_core :: ext :: codec :: Decode,
+					:: subxt :: ext :: subxt_core :: ext :: codec :: Encode,
+					:: subxt :: ext :: subxt_core :: ext :: scale_decode :: DecodeAsType,
+					:: subxt :: ext :: subxt_core :: ext :: scale_encode :: EncodeAsType,
+					Clone,
+					Debug,
+					Eq,
+					PartialEq,
+				)]
+				# [codec (crate = :: subxt :: ext :: subxt_core :: ext :: codec)]
+				#[codec(dumb_trait_bound)]
+				#[decode_as_type(
+					crate_path = ":: subxt :: ext :: subxt_core :: ext :: scale_decode"
+				)]
+				#[encode_as_type(
+					crate_path = ":: subxt :: ext :: subxt_core :: ext :: scale_encode"
+				)]
+				pub struct BlockLengthAutoScaling {
+					pub window: ::core::primitive::u32,
+					pub scale_up_threshold: runtime_types::sp_arithmetic::per_things::Perbill,
+					pub scale_down_threshold: runtime_types::sp_arithmetic::per_things::Perbill,
+				}
+				#[derive(
+					:: subxt :: ext :: subxt_core :: ext :: codec :: Decode,
+					:: subxt :: ext :: subxt_core :: ext :: codec :: Encode,
+					:: subxt :: ext :: subxt_core :: ext :: scale_decode :: DecodeAsType,
+					:: subxt :: ext :: subxt_core :: ext :: scale_encode :: EncodeAsType,
+					Clone,
+					Debug,
+					Eq,
+					PartialEq,
+				)]
+				# [codec (crate = :: subxt :: ext :: subxt_core :: ext :: codec)]
+				#[codec(dumb_trait_bound)]
+				#[decode_as_type(
+					crate_path = ":: subxt :: ext :: subxt_core :: ext :: scale_decode"
+				)]
+				#[encode_as_type(
+					crate_path = ":: subxt :: ext :: subxt_core :: ext :: scale_encode"
+				)]
+				pub enum CompressionCodec {
+					#[codec(index = 0)]
+					None,
+					#[codec(index = 1)]
+					Zstd,
+					#[codec(index = 2)]
+					Brotli,
 				}
 				#[derive(
 					:: subxt :: ext :: subxt_core :: ext :: codec :: Decode,
@@ -28178,6 +28375,8 @@ pub mod api {
 					DataSubmitted {
 						who: ::subxt::ext::subxt_core::utils::AccountId32,
 						data_hash: ::subxt::ext::subxt_core::utils::H256,
+						codec: runtime_types::da_control::pallet::CompressionCodec,
+						namespace: ::core::option::Option<::core::primitive::u32>,
 					},
 					#[codec(index = 2)]
 					BlockLengthProposalSubmitted {
@@ -36164,6 +36363,8 @@ pub mod api {
 					NotExecuted,
 					#[codec(index = 1)]
 					ExecutionSucceeded,
+					#[codec(index = 2)]
+					ExecutionFailed,
 				}
 			}
 		}
@@ -1,4 +1,5 @@
 use crate::error::ClientError;
+use crate::primitives::compression::decompress;
 use crate::rpcs;
 use crate::{
 	avail::data_availability::calls::types as DataAvailabilityCalls,
@@ -6,6 +7,7 @@ use crate::{
 };
 use crate::{
 	ABlock, AExtrinsicDetails, AExtrinsicEvents, AExtrinsics, AFoundExtrinsic, AOnlineClient,
+	CompressionCodec,
 };
 
 use primitive_types::H256;
@@ -145,6 +147,14 @@ impl Block {
 		data_submissions_by_app_id(&self.transactions, app_id)
 	}
 
+	pub fn data_submissions_by_namespace(
+		&self,
+		app_id: u32,
+		namespace: u32,
+	) -> Vec<DataSubmission> {
+		data_submissions_by_namespace(&self.transactions, app_id, namespace)
+	}
+
 	pub async fn storage_fetch<'address, T>(
 		&self,
 		address: &'address T,
@@ -225,9 +235,9 @@ pub fn transaction_all_static<T: StaticExtrinsic>(
 }
 
 pub fn data_submissions_all(transactions: &AExtrinsics) -> Vec<DataSubmission> {
-	transaction_all_static::<DataAvailabilityCalls::SubmitData>(transactions)
-		.into_iter()
-		.map(|tx| DataSubmission::from_static(tx))
+	transactions
+		.iter()
+		.filter_map(DataSubmission::from_details)
 		.collect()
 }
 
@@ -250,9 +260,9 @@ pub fn transaction_by_signer_static<T: StaticExtrinsic>(
 }
 
 pub fn data_submissions_by_signer(transactions: &AExtrinsics, signer: &str) -> Vec<DataSubmission> {
-	transaction_by_signer_static::<DataAvailabilityCalls::SubmitData>(transactions, signer)
+	transaction_by_signer(transactions, signer)
 		.into_iter()
-		.map(|tx| DataSubmission::from_static(tx))
+		.filter_map(DataSubmission::from_details)
 		.collect()
 }
 
@@ -277,8 +287,7 @@ pub fn data_submissions_by_index(
 	transactions: &AExtrinsics,
 	tx_index: u32,
 ) -> Option<DataSubmission> {
-	transaction_by_index_static::<DataAvailabilityCalls::SubmitData>(transactions, tx_index)
-		.map(|tx| DataSubmission::from_static(tx))
+	transaction_by_index(transactions, tx_index).and_then(DataSubmission::from_details)
 }
 
 pub fn transaction_by_hash(transactions: &AExtrinsics, tx_hash: H256) -> Vec<AExtrinsicDetails> {
@@ -303,10 +312,9 @@ pub fn data_submissions_by_hash(
 	transactions: &AExtrinsics,
 	tx_hash: H256,
 ) -> Option<DataSubmission> {
-	transaction_by_hash_static::<DataAvailabilityCalls::SubmitData>(transactions, tx_hash)
+	transaction_by_hash(transactions, tx_hash)
 		.into_iter()
-		.map(|tx| DataSubmission::from_static(tx))
-		.next()
+		.find_map(DataSubmission::from_details)
 }
 
 pub fn transaction_by_app_id(transactions: &AExtrinsics, app_id: u32) -> Vec<AExtrinsicDetails> {
@@ -331,10 +339,21 @@ pub fn data_submissions_by_app_id(
 	transactions: &AExtrinsics,
 	app_id: u32,
 ) -> Option<DataSubmission> {
-	transaction_by_app_id_static::<DataAvailabilityCalls::SubmitData>(transactions, app_id)
+	transaction_by_app_id(transactions, app_id)
+		.into_iter()
+		.find_map(DataSubmission::from_details)
+}
+
+pub fn data_submissions_by_namespace(
+	transactions: &AExtrinsics,
+	app_id: u32,
+	namespace: u32,
+) -> Vec<DataSubmission> {
+	transaction_by_app_id(transactions, app_id)
 		.into_iter()
-		.map(|tx| DataSubmission::from_static(tx))
-		.next()
+		.filter_map(DataSubmission::from_details)
+		.filter(|s| s.namespace == Some(namespace))
+		.collect()
 }
 
 pub fn transaction_hash_to_index(transactions: &AExtrinsics, tx_hash: H256) -> Vec<u32> {
@@ -360,28 +379,78 @@ pub fn read_app_id(transaction: &AExtrinsicDetails) -> Option<u32> {
 pub struct DataSubmission {
 	pub tx_hash: H256,
 	pub tx_index: u32,
+	/// Submitted data, decompressed if it was submitted using `submit_data_with_options`.
 	pub data: Vec<u8>,
 	pub tx_signer: Vec<u8>,
 	pub app_id: u32,
+	pub codec: CompressionCodec,
+	pub namespace: Option<u32>,
+	/// Set if the data could not be decompressed, in which case `data` keeps the submitted
+	/// bytes.
+	pub decompression_error: Option<String>,
 }
 
 impl DataSubmission {
+	/// Returns the data submission of `details` if it is a `submit_data` or
+	/// `submit_data_with_options` transaction.
+	pub fn from_details(details: AExtrinsicDetails) -> Option<Self> {
+		if let Ok(Some(value)) = details.as_extrinsic::<DataAvailabilityCalls::SubmitData>() {
+			return Some(Self::from_static(AFoundExtrinsic { details, value }));
+		}
+		if let Ok(Some(value)) =
+			details.as_extrinsic::<DataAvailabilityCalls::SubmitDataWithOptions>()
+		{
+			return Some(Self::from_options(AFoundExtrinsic { details, value }));
+		}
+		None
+	}
+
 	pub fn from_static(tx: AFoundExtrinsic<DataAvailabilityCalls::SubmitData>) -> Self {
-		let tx_hash = tx.details.hash();
-		let tx_index = tx.details.index();
-		let tx_signer = tx
-			.details
+		let data = tx.value.data.0.clone();
+		Self::new(&tx.details, data, CompressionCodec::None, None)
+	}
+
+	/// If decompression fails, `data` keeps the raw submitted bytes and `decompression_error` is
+	/// set.
+	pub fn from_options(tx: AFoundExtrinsic<DataAvailabilityCalls::SubmitDataWithOptions>) -> Self {
+		let codec = tx.value.codec.clone();
+		match decompress(&tx.value.data.0, &codec) {
+			Ok(data) => Self::new(&tx.details, data, codec, tx.value.namespace),
+			Err(e) => {
+				let mut submission = Self::new(
+					&tx.details,
+					tx.value.data.0.clone(),
+					codec,
+					tx.value.namespace,
+				);
+				submission.decompression_error = Some(e.to_string());
+				submission
+			},
+		}
+	}
+
+	fn new(
+		details: &AExtrinsicDetails,
+		data: Vec<u8>,
+		codec: CompressionCodec,
+		namespace: Option<u32>,
+	) -> Self {
+		let tx_hash = details.hash();
+		let tx_index = details.index();
+		let tx_signer = details
 			.signature_bytes()
 			.expect("DA can only be executed signed")
 			.to_vec();
-		let app_id = read_app_id(&tx.details).expect("There must be an app id");
-		let data = tx.value.data.0.clone();
+		let app_id = read_app_id(details).expect("There must be an app id");
 		Self {
 			tx_hash,
 			tx_index,
 			data,
 			tx_signer,
 			app_id,
+			codec,
+			namespace,
+			decompression_error: None,
 		}
 	}
 
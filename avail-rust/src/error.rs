@@ -2,8 +2,12 @@ use subxt::error::DispatchError;
 use subxt_signer::sr25519;
 use subxt_signer::SecretUriError;
 
+use crate::primitives::kate::KateError;
 use crate::transactions::TransactionFailed;
 use crate::utils::TransactionExecutionError;
+use crate::BlockHash;
+use serde::Deserialize;
+use subxt::ext::jsonrpsee::core::client::Error as JsonRpseeError;
 
 type RpcError = subxt::backend::rpc::reconnecting_rpc_client::Error;
 
@@ -16,6 +20,7 @@ pub enum ClientError {
 	Subxt(subxt::Error),
 	SubxtSigner(SecretUriError),
 	Sr25519(sr25519::Error),
+	Kate(KateRpcError),
 }
 
 impl ClientError {
@@ -28,6 +33,7 @@ impl ClientError {
 			ClientError::Subxt(e) => e.to_string(),
 			ClientError::SubxtSigner(e) => e.to_string(),
 			ClientError::Sr25519(e) => e.to_string(),
+			ClientError::Kate(e) => e.message.clone(),
 		}
 	}
 }
@@ -85,3 +91,81 @@ impl From<serde_json::Error> for ClientError {
 		Self::SerdeJson(value)
 	}
 }
+
+/// Error returned by the Kate RPC of the node.
+#[derive(Debug, Clone)]
+pub struct KateRpcError {
+	pub kind: KateRpcErrorKind,
+	pub message: String,
+	pub data: Option<KateRpcErrorData>,
+}
+
+/// Compatible with `kate_rpc::Error`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KateRpcErrorKind {
+	Internal,
+	BlockNotFinalized,
+	MissingBlock,
+	EmptyCommitments,
+	CellsLimitExceeded,
+	KateRuntime,
+	MissingTransaction,
+	IncludedLeaf,
+	UnsupportedRuntime,
+	NonCanonicalBlock,
+}
+
+impl KateRpcErrorKind {
+	pub fn from_code(code: i32) -> Option<Self> {
+		let kind = match code {
+			1 => Self::Internal,
+			2 => Self::BlockNotFinalized,
+			3 => Self::MissingBlock,
+			4 => Self::EmptyCommitments,
+			5 => Self::CellsLimitExceeded,
+			6 => Self::KateRuntime,
+			7 => Self::MissingTransaction,
+			8 => Self::IncludedLeaf,
+			9 => Self::UnsupportedRuntime,
+			10 => Self::NonCanonicalBlock,
+			_ => return None,
+		};
+		Some(kind)
+	}
+
+	/// Returns true if the same request may succeed later, e.g. once the block is finalized.
+	pub fn is_retryable(&self) -> bool {
+		matches!(self, Self::BlockNotFinalized | Self::MissingBlock)
+	}
+}
+
+/// Compatible with `kate_rpc::ErrorData`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KateRpcErrorData {
+	pub block_hash: Option<BlockHash>,
+	pub requested: Option<usize>,
+	pub limit: Option<usize>,
+	pub kate_error: Option<KateError>,
+}
+
+impl ClientError {
+	/// Converts errors of Kate RPC calls, keeping the Kate error code and data if there are any.
+	pub(crate) fn from_kate(value: RpcError) -> Self {
+		let RpcError::RpcError(JsonRpseeError::Call(err)) = &value else {
+			return Self::RpcError(value);
+		};
+		let Some(kind) = KateRpcErrorKind::from_code(err.code()) else {
+			return Self::RpcError(value);
+		};
+		let data = err
+			.data()
+			.and_then(|data| serde_json::from_str(data.get()).ok());
+
+		Self::Kate(KateRpcError {
+			kind,
+			message: err.message().to_string(),
+			data,
+		})
+	}
+}
@@ -17,6 +17,7 @@ pub type RewardDestination =
 
 pub use api_dev::api::data_availability::calls::types::create_application_key::Key;
 pub use api_dev::api::data_availability::calls::types::submit_data::Data;
+pub use api_dev::api::runtime_types::da_control::pallet::CompressionCodec;
 pub use api_dev::api::runtime_types::frame_support::dispatch::DispatchFeeModifier;
 pub use api_dev::api::runtime_types::pallet_staking::ValidatorPrefs;
 pub use primitive_types::H256;
@@ -34,7 +35,7 @@ pub use kate_recovery;
 pub use primitives::block::{
 	AppUncheckedExtrinsic, AvailHeader, DefaultExtrinsicParams, DefaultExtrinsicParamsBuilder,
 };
-pub use primitives::kate::{Cell, GDataProof, GRow};
+pub use primitives::kate::{Blob, Cell, GDataProof, GRow};
 pub use sp_core;
 pub use subxt;
 pub use subxt::config::polkadot::U256;
@@ -0,0 +1,70 @@
+use crate::error::ClientError;
+use crate::CompressionCodec;
+use std::io::{Read, Write};
+
+const BROTLI_BUFFER_SIZE: usize = 4096;
+const BROTLI_QUALITY: u32 = 11;
+const BROTLI_LG_WINDOW_SIZE: u32 = 22;
+
+/// Compresses `data` using `codec`. `CompressionCodec::None` returns `data` unchanged.
+pub fn compress(data: &[u8], codec: &CompressionCodec) -> Result<Vec<u8>, ClientError> {
+	match codec {
+		CompressionCodec::None => Ok(data.to_vec()),
+		CompressionCodec::Zstd => zstd_compress(data),
+		CompressionCodec::Brotli => {
+			let mut compressed = Vec::new();
+			{
+				let mut writer = brotli::CompressorWriter::new(
+					&mut compressed,
+					BROTLI_BUFFER_SIZE,
+					BROTLI_QUALITY,
+					BROTLI_LG_WINDOW_SIZE,
+				);
+				writer
+					.write_all(data)
+					.map_err(|e| ClientError::from(format!("Brotli compression failed: {e}")))?;
+			}
+			Ok(compressed)
+		},
+	}
+}
+
+/// Decompresses `data` which was compressed using `codec`.
+pub fn decompress(data: &[u8], codec: &CompressionCodec) -> Result<Vec<u8>, ClientError> {
+	match codec {
+		CompressionCodec::None => Ok(data.to_vec()),
+		CompressionCodec::Zstd => zstd_decompress(data),
+		CompressionCodec::Brotli => {
+			let mut decompressed = Vec::new();
+			brotli::Decompressor::new(data, BROTLI_BUFFER_SIZE)
+				.read_to_end(&mut decompressed)
+				.map_err(|e| ClientError::from(format!("Brotli decompression failed: {e}")))?;
+			Ok(decompressed)
+		},
+	}
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn zstd_compress(data: &[u8]) -> Result<Vec<u8>, ClientError> {
+	zstd::encode_all(data, zstd::DEFAULT_COMPRESSION_LEVEL)
+		.map_err(|e| ClientError::from(format!("Zstd compression failed: {e}")))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn zstd_decompress(data: &[u8]) -> Result<Vec<u8>, ClientError> {
+	zstd::decode_all(data).map_err(|e| ClientError::from(format!("Zstd decompression failed: {e}")))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn zstd_compress(_data: &[u8]) -> Result<Vec<u8>, ClientError> {
+	Err(ClientError::from(
+		"Zstd compression is not supported on wasm32",
+	))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn zstd_decompress(_data: &[u8]) -> Result<Vec<u8>, ClientError> {
+	Err(ClientError::from(
+		"Zstd decompression is not supported on wasm32",
+	))
+}
@@ -3,6 +3,7 @@ use codec::{Decode, Encode};
 use derive_more::Constructor;
 use serde::{Deserialize, Serialize};
 use sp_core::ConstU32;
+use subxt::backend::legacy::rpc_methods::Bytes;
 
 /// Compatible with `kate::com::Cell`
 #[derive(Clone, Constructor, Debug, Serialize, Deserialize, Encode, Decode)]
@@ -57,3 +58,27 @@ impl TryFrom<Vec<u8>> for GProof {
 		Ok(GProof(proof))
 	}
 }
+
+/// Compatible with `kate_rpc::Blob`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Blob {
+	pub tx_index: u32,
+	pub namespace: Option<u32>,
+	pub data: Bytes,
+}
+
+/// Compatible with `da_runtime::kate::Error`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KateError {
+	TryFromInt,
+	MissingRow(u32),
+	InvalidScalarAtRow(u32),
+	KateGrid,
+	InvalidDimension,
+	AppRow,
+	MissingCell { row: u32, col: u32 },
+	Proof,
+	ColumnExtension,
+	InvalidMultiproofGrid { rows: u32, cols: u32 },
+}
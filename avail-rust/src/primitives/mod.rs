@@ -1,2 +1,3 @@
 pub mod block;
+pub mod compression;
 pub mod kate;
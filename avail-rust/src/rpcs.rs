@@ -3,7 +3,7 @@ use avail_core::data_proof::ProofResponse;
 use crate::avail::runtime_types::frame_system::limits::BlockLength;
 use crate::error::ClientError;
 use crate::from_substrate::{FeeDetails, NodeRole, PeerInfo, RuntimeDispatchInfo, SyncState};
-use crate::{ABlockDetailsRPC, AvailHeader, BlockHash, BlockNumber, Cell, GDataProof, GRow};
+use crate::{ABlockDetailsRPC, AvailHeader, Blob, BlockHash, BlockNumber, Cell, GDataProof, GRow};
 use subxt::backend::legacy::rpc_methods::{Bytes, SystemHealth};
 use subxt::backend::rpc::reconnecting_rpc_client::RpcClient;
 use subxt::rpc_params;
@@ -357,6 +357,15 @@ impl Kate {
 	) -> Result<Vec<GRow>, ClientError> {
 		query_rows(&self.client, rows, at).await
 	}
+
+	pub async fn query_blobs(
+		&self,
+		app_id: u32,
+		namespace: Option<u32>,
+		at: Option<BlockHash>,
+	) -> Result<Vec<Blob>, ClientError> {
+		query_blobs(&self.client, app_id, namespace, at).await
+	}
 }
 
 pub async fn block_length(
@@ -364,7 +373,10 @@ pub async fn block_length(
 	at: Option<BlockHash>,
 ) -> Result<BlockLength, ClientError> {
 	let params = rpc_params![at].build();
-	let value = client.request("kate_blockLength".into(), params).await?;
+	let value = client
+		.request("kate_blockLength".into(), params)
+		.await
+		.map_err(ClientError::from_kate)?;
 	Ok(serde_json::from_str(value.get())?)
 }
 
@@ -374,7 +386,10 @@ pub async fn query_data_proof(
 	at: Option<BlockHash>,
 ) -> Result<ProofResponse, ClientError> {
 	let params = rpc_params![transaction_index, at].build();
-	let value = client.request("kate_queryDataProof".into(), params).await?;
+	let value = client
+		.request("kate_queryDataProof".into(), params)
+		.await
+		.map_err(ClientError::from_kate)?;
 	Ok(serde_json::from_str(value.get())?)
 }
 
@@ -384,7 +399,10 @@ pub async fn query_proof(
 	at: Option<BlockHash>,
 ) -> Result<Vec<GDataProof>, ClientError> {
 	let params = rpc_params![cells, at].build();
-	let value = client.request("kate_queryProof".into(), params).await?;
+	let value = client
+		.request("kate_queryProof".into(), params)
+		.await
+		.map_err(ClientError::from_kate)?;
 	Ok(serde_json::from_str(value.get())?)
 }
 
@@ -394,6 +412,23 @@ pub async fn query_rows(
 	at: Option<BlockHash>,
 ) -> Result<Vec<GRow>, ClientError> {
 	let params = rpc_params![rows, at].build();
-	let value = client.request("kate_queryRows".into(), params).await?;
+	let value = client
+		.request("kate_queryRows".into(), params)
+		.await
+		.map_err(ClientError::from_kate)?;
+	Ok(serde_json::from_str(value.get())?)
+}
+
+pub async fn query_blobs(
+	client: &RpcClient,
+	app_id: u32,
+	namespace: Option<u32>,
+	at: Option<BlockHash>,
+) -> Result<Vec<Blob>, ClientError> {
+	let params = rpc_params![app_id, namespace, at].build();
+	let value = client
+		.request("kate_queryBlobs".into(), params)
+		.await
+		.map_err(ClientError::from_kate)?;
 	Ok(serde_json::from_str(value.get())?)
 }
@@ -1,11 +1,14 @@
 use crate::api_dev::api::data_availability::calls::types::create_application_key::Key;
 use crate::api_dev::api::data_availability::calls::types::submit_data::Data;
-use crate::{avail, AOnlineClient};
+use crate::error::ClientError;
+use crate::primitives::compression::compress;
+use crate::{avail, AOnlineClient, CompressionCodec};
 
 use super::Transaction;
 use subxt::backend::rpc::reconnecting_rpc_client::RpcClient;
 
 pub type SubmitDataCall = avail::data_availability::calls::types::SubmitData;
+pub type SubmitDataWithOptionsCall = avail::data_availability::calls::types::SubmitDataWithOptions;
 pub type CreateApplicationKeyCall = avail::data_availability::calls::types::CreateApplicationKey;
 
 #[derive(Clone)]
@@ -28,6 +31,27 @@ impl DataAvailability {
 		Transaction::new(self.online_client.clone(), self.rpc_client.clone(), payload)
 	}
 
+	/// Compresses `data` using `codec` and submits it, tagged with `namespace` if it is set.
+	/// Fees are charged on the compressed length.
+	pub fn submit_data_with_options(
+		&self,
+		data: Vec<u8>,
+		codec: CompressionCodec,
+		namespace: Option<u32>,
+	) -> Result<Transaction<SubmitDataWithOptionsCall>, ClientError> {
+		let data = Data {
+			0: compress(&data, &codec)?,
+		};
+		let payload = avail::tx()
+			.data_availability()
+			.submit_data_with_options(data, codec, namespace);
+		Ok(Transaction::new(
+			self.online_client.clone(),
+			self.rpc_client.clone(),
+			payload,
+		))
+	}
+
 	pub fn create_application_key(&self, key: Vec<u8>) -> Transaction<CreateApplicationKeyCall> {
 		let key = Key { 0: key };
 		let payload = avail::tx().data_availability().create_application_key(key);
@@ -9,10 +9,13 @@ use avail_core::{traits::GetAppId, AppId};
 use binary_merkle_tree::{merkle_proof, merkle_root, MerkleProof};
 use codec::{Decode, Encode};
 use derive_more::Constructor;
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_core::H256;
-use sp_io::hashing::keccak_256;
+use sp_io::hashing::{blake2_256, keccak_256};
 use sp_runtime_interface::pass_by::PassByCodec;
-use sp_std::{iter::repeat, vec::Vec};
+use sp_std::{iter::repeat, vec, vec::Vec};
 
 #[derive(Constructor, Debug, Encode, Decode, Clone, PartialEq, Eq)]
 pub struct BridgedData {
@@ -20,11 +23,25 @@ pub struct BridgedData {
 	pub addr_msg: AddressedMessage,
 }
 
-#[derive(Debug, Constructor, Encode, Decode, PartialEq, Eq)]
+/// Tag which splits the data submissions of one `AppId` into several streams.
+pub type Namespace = u32;
+
+#[derive(Debug, Constructor, Encode, Decode, Clone, PartialEq, Eq)]
 pub struct SubmittedData {
 	pub id: AppId,
 	pub tx_index: u32,
 	pub data: Vec<u8>,
+	pub namespace: Option<Namespace>,
+}
+
+impl SubmittedData {
+	/// Returns the leaf of this submission in the submitted tree.
+	///
+	/// It is `keccak_256(data)` whether or not the submission is tagged, so data and
+	/// non-inclusion proofs are verified the same way for every submission.
+	pub fn leaf(&self) -> H256 {
+		H256(keccak_256(&self.data))
+	}
 }
 
 impl GetAppId for SubmittedData {
@@ -40,6 +57,38 @@ pub struct ExtractedTxData {
 	pub bridge_data: Option<BridgedData>,
 }
 
+/// Compact Merkle proof of several leaves of the same balanced `Keccak256` tree.
+///
+/// Sibling hashes shared between leaves are only included once.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct MultiProof {
+	/// Root hash of the tree.
+	pub root: H256,
+	/// Hashes which cannot be computed from `leaves`, layer by layer from the leaves up, and
+	/// from left to right inside each layer.
+	pub proof: Vec<H256>,
+	/// Number of leaves of the tree, always a power of two.
+	pub number_of_leaves: u32,
+	/// Indices of the proven leaves, in ascending order.
+	pub leaf_indices: Vec<u32>,
+	/// Proven leaves, in the same order as `leaf_indices`.
+	pub leaves: Vec<H256>,
+}
+
+/// Multi-proof of several data submissions of a block against its `data_root`.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct DataMultiProof {
+	pub data_root: H256,
+	/// Root of the bridge sub-trie, needed to rebuild `data_root` from the submitted root.
+	pub bridged_root: H256,
+	/// Proof of the submissions against the submitted root.
+	pub proof: MultiProof,
+}
+
 #[derive(Debug, Default, PassByCodec, Encode, Decode)]
 pub struct HeaderExtensionBuilderData {
 	pub app_extrinsics: Vec<AppExtrinsic>,
@@ -143,6 +192,68 @@ impl HeaderExtensionBuilderData {
 		Some(proof)
 	}
 
+	/// Generates a single proof of the data submissions at `leaf_indices`, see
+	/// [`Self::submitted_root`].
+	pub fn submitted_multi_proof_of(&self, leaf_indices: &[usize]) -> Option<MultiProof> {
+		if self.data_submissions.is_empty() {
+			return None;
+		}
+
+		multi_proof(self.balanced_submitted().collect(), leaf_indices)
+	}
+
+	/// Generates a single proof of the data submissions of the transactions at `tx_indices`
+	/// against the `data_root`.
+	///
+	/// It returns `None` if any of the transactions is not a data submission.
+	pub fn data_multi_proof(&self, tx_indices: &[u32]) -> Option<DataMultiProof> {
+		let leaf_indices = tx_indices
+			.iter()
+			.map(|tx_idx| {
+				self.data_submissions
+					.iter()
+					.position(|s| s.tx_index == *tx_idx)
+			})
+			.collect::<Option<Vec<_>>>()?;
+		let proof = self.submitted_multi_proof_of(&leaf_indices)?;
+		let roots = self.roots();
+
+		Some(DataMultiProof {
+			data_root: roots.data_root,
+			bridged_root: self.bridged_root(),
+			proof,
+		})
+	}
+
+	/// Generates a proof that no data submission has `leaf_hash` (`keccak(keccak(data))`) as
+	/// leaf hash, made of every leaf of the submitted tree.
+	///
+	/// It returns `None` if some data submission does have that leaf hash.
+	pub fn data_non_inclusion_proof(&self, leaf_hash: H256) -> Option<DataMultiProof> {
+		let proof = if self.data_submissions.is_empty() {
+			MultiProof {
+				root: H256::zero(),
+				proof: Vec::new(),
+				number_of_leaves: 0,
+				leaf_indices: Vec::new(),
+				leaves: Vec::new(),
+			}
+		} else {
+			let leaves = self.balanced_submitted().collect::<Vec<_>>();
+			if leaves.iter().any(|leaf| leaf_hash_of(leaf) == leaf_hash) {
+				return None;
+			}
+			let leaf_indices = (0..leaves.len()).collect::<Vec<_>>();
+			multi_proof(leaves, &leaf_indices)?
+		};
+
+		Some(DataMultiProof {
+			data_root: self.data_root(),
+			bridged_root: self.bridged_root(),
+			proof,
+		})
+	}
+
 	pub fn leaf_idx(&self, tx_idx: u32) -> Option<(usize, SubTrie)> {
 		if let Some(idx) = self
 			.data_submissions
@@ -160,6 +271,27 @@ impl HeaderExtensionBuilderData {
 		}
 		None
 	}
+
+	/// Returns the transaction index of the data submission whose `blake2_256` hash is
+	/// `data_hash`, as reported by `DataSubmitted` events.
+	pub fn data_submission_tx_idx(&self, data_hash: H256) -> Option<u32> {
+		self.data_submissions
+			.iter()
+			.find(|s| H256(blake2_256(&s.data)) == data_hash)
+			.map(|s| s.tx_index)
+	}
+
+	/// Returns the data submissions of `app_id`, only those tagged with `namespace` if it is
+	/// set.
+	pub fn data_submissions_of(
+		&self,
+		app_id: AppId,
+		namespace: Option<Namespace>,
+	) -> impl Iterator<Item = &SubmittedData> + '_ {
+		self.data_submissions
+			.iter()
+			.filter(move |s| s.id == app_id && namespace.map_or(true, |ns| s.namespace == Some(ns)))
+	}
 }
 
 impl HeaderExtensionBuilderData {
@@ -167,7 +299,7 @@ impl HeaderExtensionBuilderData {
 		let balanced_len = next_power_of_two(&self.data_submissions);
 		self.data_submissions
 			.iter()
-			.map(|s| H256(keccak_256(&s.data)))
+			.map(SubmittedData::leaf)
 			.chain(repeat(H256::zero()))
 			.take(balanced_len)
 	}
@@ -212,6 +344,159 @@ impl From<Vec<ExtractedTxData>> for HeaderExtensionBuilderData {
 	}
 }
 
+/// Verifies that `proof` proves its leaves against `proof.root`.
+pub fn verify_multi_proof(proof: &MultiProof) -> bool {
+	let MultiProof {
+		root,
+		proof,
+		number_of_leaves,
+		leaf_indices,
+		leaves,
+	} = proof;
+
+	if !number_of_leaves.is_power_of_two()
+		|| leaf_indices.is_empty()
+		|| leaf_indices.len() != leaves.len()
+		|| leaf_indices.windows(2).any(|w| w[0] >= w[1])
+		|| leaf_indices.iter().any(|idx| idx >= number_of_leaves)
+	{
+		return false;
+	}
+
+	let mut nodes = leaf_indices
+		.iter()
+		.zip(leaves)
+		.map(|(idx, leaf)| (*idx, leaf_hash_of(leaf)))
+		.collect::<Vec<_>>();
+	let mut proof = proof.iter();
+	let mut width = *number_of_leaves;
+
+	while width > 1 {
+		let mut parents = Vec::with_capacity(nodes.len());
+		let mut nodes_iter = nodes.iter().peekable();
+		while let Some(&(idx, hash)) = nodes_iter.next() {
+			let (left, right) = match nodes_iter.peek() {
+				Some(&&(next_idx, next_hash)) if next_idx == idx ^ 1 => {
+					nodes_iter.next();
+					(hash, next_hash)
+				},
+				_ => {
+					let Some(sibling) = proof.next() else {
+						return false;
+					};
+					if idx % 2 == 0 {
+						(hash, *sibling)
+					} else {
+						(*sibling, hash)
+					}
+				},
+			};
+			parents.push((idx / 2, hash_pair(&left, &right)));
+		}
+		nodes = parents;
+		width /= 2;
+	}
+
+	proof.next().is_none() && nodes.as_slice() == [(0, *root)]
+}
+
+/// Verifies that `proof` proves its data submissions against `data_root`.
+pub fn verify_data_multi_proof(data_root: H256, proof: &DataMultiProof) -> bool {
+	is_data_root_of(data_root, proof) && verify_multi_proof(&proof.proof)
+}
+
+/// Verifies that `proof` contains every leaf of the submitted tree of `data_root`, and none of
+/// them has `leaf_hash` (`keccak(keccak(data))`) as leaf hash.
+pub fn verify_data_non_inclusion_proof(
+	data_root: H256,
+	leaf_hash: H256,
+	proof: &DataMultiProof,
+) -> bool {
+	let MultiProof {
+		root,
+		proof: siblings,
+		number_of_leaves,
+		leaves,
+		..
+	} = &proof.proof;
+
+	let is_complete = if *number_of_leaves == 0 {
+		// Blocks without data submissions have a zero submitted root.
+		root.is_zero()
+			&& siblings.is_empty()
+			&& leaves.is_empty()
+			&& is_data_root_of(data_root, proof)
+	} else {
+		leaves.len() as u64 == u64::from(*number_of_leaves)
+			&& verify_data_multi_proof(data_root, proof)
+	};
+
+	is_complete && leaves.iter().all(|leaf| leaf_hash_of(leaf) != leaf_hash)
+}
+
+fn is_data_root_of(data_root: H256, proof: &DataMultiProof) -> bool {
+	let roots = TxDataRoots::new(proof.proof.root, proof.bridged_root);
+	proof.data_root == data_root && roots.data_root == data_root
+}
+
+fn leaf_hash_of(leaf: &H256) -> H256 {
+	H256(keccak_256(leaf.as_bytes()))
+}
+
+/// Creates the compact proof of the leaves at `leaf_indices` of the balanced tree of `leaves`,
+/// using the same hashing as `merkle_root::<Keccak256, _>`.
+fn multi_proof(leaves: Vec<H256>, leaf_indices: &[usize]) -> Option<MultiProof> {
+	let mut indices = leaf_indices.to_vec();
+	indices.sort_unstable();
+	indices.dedup();
+	if indices.is_empty()
+		|| !leaves.len().is_power_of_two()
+		|| indices.iter().any(|idx| *idx >= leaves.len())
+	{
+		return None;
+	}
+
+	let number_of_leaves = u32::try_from(leaves.len()).ok()?;
+	let leaf_indices = indices
+		.iter()
+		.map(|idx| u32::try_from(*idx).ok())
+		.collect::<Option<Vec<_>>>()?;
+	let proven_leaves = indices.iter().map(|idx| leaves[*idx]).collect();
+
+	let mut layer = leaves.iter().map(leaf_hash_of).collect::<Vec<_>>();
+	let mut proof = vec![];
+	while layer.len() > 1 {
+		let mut parents = Vec::with_capacity(indices.len());
+		let mut indices_iter = indices.iter().peekable();
+		while let Some(&idx) = indices_iter.next() {
+			if indices_iter.next_if_eq(&&(idx ^ 1)).is_none() {
+				proof.push(layer[idx ^ 1]);
+			}
+			parents.push(idx / 2);
+		}
+		indices = parents;
+		layer = layer
+			.chunks(2)
+			.map(|pair| hash_pair(&pair[0], &pair[1]))
+			.collect();
+	}
+
+	Some(MultiProof {
+		root: layer[0],
+		proof,
+		number_of_leaves,
+		leaf_indices,
+		leaves: proven_leaves,
+	})
+}
+
+fn hash_pair(left: &H256, right: &H256) -> H256 {
+	let mut combined = [0u8; 64];
+	combined[..32].copy_from_slice(left.as_bytes());
+	combined[32..].copy_from_slice(right.as_bytes());
+	H256(keccak_256(&combined))
+}
+
 #[inline]
 fn next_power_of_two<T>(s: &[T]) -> usize {
 	s.len().checked_next_power_of_two().unwrap_or(1)
@@ -3,5 +3,9 @@ pub mod builder_data;
 pub mod traits;
 
 // Reexport
-pub use builder_data::{BridgedData, ExtractedTxData, HeaderExtensionBuilderData, SubmittedData};
+pub use builder_data::{
+	verify_data_multi_proof, verify_data_non_inclusion_proof, verify_multi_proof, BridgedData,
+	DataMultiProof, ExtractedTxData, HeaderExtensionBuilderData, MultiProof, Namespace,
+	SubmittedData,
+};
 pub use traits::HeaderExtensionDataFilter;
@@ -1,7 +1,7 @@
 use core::time::Duration;
 use std::time::Instant;
 
-use substrate_prometheus_endpoint::{Histogram, PrometheusError, Registry};
+use substrate_prometheus_endpoint::{register, Counter, Histogram, PrometheusError, Registry, U64};
 
 use crate::metrics::LOG_TARGET;
 
@@ -172,8 +172,18 @@ impl HeaderExtensionBuilderMetrics {
 pub struct KateRpcMetrics {
 	pub query_rows_execution_time: Histogram,
 	pub query_proof_execution_time: Histogram,
+	pub query_proof_batch_execution_time: Histogram,
+	pub query_multiproof_execution_time: Histogram,
 	pub query_block_length_execution_time: Histogram,
 	pub query_data_proof_execution_time: Histogram,
+	pub query_data_proof_by_tx_hash_execution_time: Histogram,
+	pub query_data_proof_by_data_hash_execution_time: Histogram,
+	pub query_data_proofs_execution_time: Histogram,
+	pub query_data_non_inclusion_proof_execution_time: Histogram,
+	pub query_app_data_execution_time: Histogram,
+	pub query_blobs_execution_time: Histogram,
+	pub grid_cache_hits: Counter<U64>,
+	pub grid_cache_misses: Counter<U64>,
 }
 
 impl KateRpcMetrics {
@@ -209,6 +219,18 @@ impl KateRpcMetrics {
 			"Kate RPC - Query Proof Time in microseconds",
 			buckets.to_vec(),
 		)?;
+		let query_proof_batch_execution_time = custom_histogram(
+			registry,
+			"avail_kate_rpc_query_proof_batch_execution_time",
+			"Kate RPC - Query Proof Batch Time in microseconds",
+			buckets.to_vec(),
+		)?;
+		let query_multiproof_execution_time = custom_histogram(
+			registry,
+			"avail_kate_rpc_query_multiproof_execution_time",
+			"Kate RPC - Query MultiProof Time in microseconds",
+			buckets.to_vec(),
+		)?;
 
 		let buckets = [
 			100.0, 200.0, 300.0, 400.0, 500.0, // 0.10ms, 0.20ms, 0.30ms, 0.40ms, 0.50ms,
@@ -233,12 +255,79 @@ impl KateRpcMetrics {
 			"Kate RPC - Query Data Proof Time in microseconds",
 			buckets.to_vec(),
 		)?;
+		let query_data_proof_by_tx_hash_execution_time = custom_histogram(
+			registry,
+			"avail_kate_rpc_query_data_proof_by_tx_hash_execution_time",
+			"Kate RPC - Query Data Proof By Tx Hash Time in microseconds",
+			buckets.to_vec(),
+		)?;
+		let query_data_proof_by_data_hash_execution_time = custom_histogram(
+			registry,
+			"avail_kate_rpc_query_data_proof_by_data_hash_execution_time",
+			"Kate RPC - Query Data Proof By Data Hash Time in microseconds",
+			buckets.to_vec(),
+		)?;
+		let query_data_proofs_execution_time = custom_histogram(
+			registry,
+			"avail_kate_rpc_query_data_proofs_execution_time",
+			"Kate RPC - Query Data Proofs Time in microseconds",
+			buckets.to_vec(),
+		)?;
+		let query_data_non_inclusion_proof_execution_time = custom_histogram(
+			registry,
+			"avail_kate_rpc_query_data_non_inclusion_proof_execution_time",
+			"Kate RPC - Query Data Non-Inclusion Proof Time in microseconds",
+			buckets.to_vec(),
+		)?;
+
+		let buckets = [
+			1000.0, 5000.0, 10000.0, 25000.0, //  1ms, 5ms, 10ms, 25ms
+			50000.0, 75000.0, 100_000.0, 150_000.0, // 50ms, 75ms, 100ms, 150ms
+			200_000.0, 300_000.0, 400_000.0, 500_000.0, // 200ms, 300ms, 400ms, 500ms
+		];
+		let query_app_data_execution_time = custom_histogram(
+			registry,
+			"avail_kate_rpc_query_app_data_execution_time",
+			"Kate RPC - Query App Data Time in microseconds",
+			buckets.to_vec(),
+		)?;
+		let query_blobs_execution_time = custom_histogram(
+			registry,
+			"avail_kate_rpc_query_blobs_execution_time",
+			"Kate RPC - Query Blobs Time in microseconds",
+			buckets.to_vec(),
+		)?;
+
+		let grid_cache_hits = register(
+			Counter::new(
+				"avail_kate_rpc_grid_cache_hits",
+				"Kate RPC - Grid cache hits",
+			)?,
+			registry,
+		)?;
+		let grid_cache_misses = register(
+			Counter::new(
+				"avail_kate_rpc_grid_cache_misses",
+				"Kate RPC - Grid cache misses",
+			)?,
+			registry,
+		)?;
 
 		Ok(Self {
 			query_rows_execution_time,
 			query_proof_execution_time,
+			query_proof_batch_execution_time,
+			query_multiproof_execution_time,
 			query_block_length_execution_time,
 			query_data_proof_execution_time,
+			query_data_proof_by_tx_hash_execution_time,
+			query_data_proof_by_data_hash_execution_time,
+			query_data_proofs_execution_time,
+			query_data_non_inclusion_proof_execution_time,
+			query_app_data_execution_time,
+			query_blobs_execution_time,
+			grid_cache_hits,
+			grid_cache_misses,
 		})
 	}
 
@@ -260,6 +349,24 @@ impl KateRpcMetrics {
 		}
 	}
 
+	pub(crate) fn observe_query_proof_batch_execution_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.kate_rpc
+				.query_proof_batch_execution_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
+
+	pub(crate) fn observe_query_multiproof_execution_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.kate_rpc
+				.query_multiproof_execution_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
+
 	pub(crate) fn observe_query_block_length_execution_time(duration: Duration) {
 		if let Some(metrics) = AVAIL_METRICS.get() {
 			metrics
@@ -277,6 +384,72 @@ impl KateRpcMetrics {
 				.observe(duration.as_micros() as f64);
 		}
 	}
+
+	pub(crate) fn observe_query_data_proof_by_tx_hash_execution_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.kate_rpc
+				.query_data_proof_by_tx_hash_execution_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
+
+	pub(crate) fn observe_query_data_proof_by_data_hash_execution_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.kate_rpc
+				.query_data_proof_by_data_hash_execution_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
+
+	pub(crate) fn observe_query_data_proofs_execution_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.kate_rpc
+				.query_data_proofs_execution_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
+
+	pub(crate) fn observe_query_data_non_inclusion_proof_execution_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.kate_rpc
+				.query_data_non_inclusion_proof_execution_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
+
+	pub(crate) fn observe_query_app_data_execution_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.kate_rpc
+				.query_app_data_execution_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
+
+	pub(crate) fn observe_query_blobs_execution_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.kate_rpc
+				.query_blobs_execution_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
+
+	pub fn observe_grid_cache_hit() {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics.kate_rpc.grid_cache_hits.inc();
+		}
+	}
+
+	pub fn observe_grid_cache_miss() {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics.kate_rpc.grid_cache_misses.inc();
+		}
+	}
 }
 
 pub struct ImportBlockMetrics {
@@ -325,9 +498,17 @@ impl ImportBlockMetrics {
 pub enum ObserveKind {
 	ImportBlockTotalExecutionTime,
 	KateQueryDataProof,
+	KateQueryDataProofByTxHash,
+	KateQueryDataProofByDataHash,
+	KateQueryDataProofs,
+	KateQueryDataNonInclusionProof,
 	KateQueryBlockLength,
 	KateQueryProof,
+	KateQueryProofBatch,
+	KateQueryMultiProof,
 	KateQueryRows,
+	KateQueryAppData,
+	KateQueryBlobs,
 	HETotalExecutionTime,
 	HEGrid,
 	HECommitment,
@@ -357,15 +538,39 @@ impl Drop for MetricObserver {
 			ObserveKind::KateQueryDataProof => {
 				KateRpcMetrics::observe_query_data_proof_execution_time(duration)
 			},
+			ObserveKind::KateQueryDataProofByTxHash => {
+				KateRpcMetrics::observe_query_data_proof_by_tx_hash_execution_time(duration)
+			},
+			ObserveKind::KateQueryDataProofByDataHash => {
+				KateRpcMetrics::observe_query_data_proof_by_data_hash_execution_time(duration)
+			},
+			ObserveKind::KateQueryDataProofs => {
+				KateRpcMetrics::observe_query_data_proofs_execution_time(duration)
+			},
+			ObserveKind::KateQueryDataNonInclusionProof => {
+				KateRpcMetrics::observe_query_data_non_inclusion_proof_execution_time(duration)
+			},
 			ObserveKind::KateQueryBlockLength => {
 				KateRpcMetrics::observe_query_block_length_execution_time(duration)
 			},
 			ObserveKind::KateQueryProof => {
 				KateRpcMetrics::observe_query_proof_execution_time(duration)
 			},
+			ObserveKind::KateQueryProofBatch => {
+				KateRpcMetrics::observe_query_proof_batch_execution_time(duration)
+			},
+			ObserveKind::KateQueryMultiProof => {
+				KateRpcMetrics::observe_query_multiproof_execution_time(duration)
+			},
 			ObserveKind::KateQueryRows => {
 				KateRpcMetrics::observe_query_rows_execution_time(duration)
 			},
+			ObserveKind::KateQueryAppData => {
+				KateRpcMetrics::observe_query_app_data_execution_time(duration)
+			},
+			ObserveKind::KateQueryBlobs => {
+				KateRpcMetrics::observe_query_blobs_execution_time(duration)
+			},
 			ObserveKind::HETotalExecutionTime => {
 				HeaderExtensionBuilderMetrics::observe_total_execution_time(duration)
 			},
@@ -20,6 +20,7 @@
 
 // FIXME #1021 move this into sp-consensus
 use avail_base::{PostInherentsBackend, PostInherentsProvider};
+use da_control::DaInvalidTransactionCustomId;
 
 use codec::Encode;
 use futures::{
@@ -39,6 +40,7 @@ use sp_core::traits::SpawnNamed;
 use sp_inherents::InherentData;
 use sp_runtime::{
 	traits::{BlakeTwo256, Block as BlockT, Hash as HashT, Header as HeaderT},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
 	Digest, Percent, SaturatedConversion,
 };
 use std::{marker::PhantomData, pin::Pin, sync::Arc, time};
@@ -317,6 +319,14 @@ where
 /// It allows us to increase block utilization.
 const MAX_SKIPPED_TRANSACTIONS: usize = 8;
 
+/// Whether `e` is raised by a transaction which does not fit into the remaining block space
+/// quota of its application. Unlike invalid transactions, it can be included in next blocks.
+fn exhausts_app_quota(e: &TransactionValidityError) -> bool {
+	*e == TransactionValidityError::Invalid(InvalidTransaction::Custom(
+		DaInvalidTransactionCustomId::AppQuotaExceeded as u8,
+	))
+}
+
 impl<A, Block, C, PR> Proposer<Block, C, A, PR>
 where
 	A: TransactionPool<Block = Block>,
@@ -575,6 +585,15 @@ where
 						break EndProposingReason::HitBlockWeightLimit;
 					}
 				},
+				Err(ApplyExtrinsicFailed(Validity(e))) if exhausts_app_quota(&e) => {
+					pending_iterator.report_invalid(&pending_tx);
+					debug!(
+						target: LOG_TARGET,
+						"[{:?}] Block space quota of the application is exhausted, \
+						 keeping the transaction for the next blocks.",
+						pending_tx_hash,
+					);
+				},
 				Err(e) => {
 					pending_iterator.report_invalid(&pending_tx);
 					debug!(
@@ -58,6 +58,18 @@ pub struct Cli {
 	/// Max size cannot exceed 10_000
 	#[arg(long, default_value_t = 64, value_parser=kate_max_cells_size_upper_bound)]
 	pub kate_max_cells_size: usize,
+
+	/// Allow Kate RPC queries against best blocks which are not finalized yet.
+	///
+	/// Callers still have to opt in on each request, and such responses are marked as unfinalized.
+	#[arg(long, default_value_t = false)]
+	pub kate_allow_unfinalized: bool,
+
+	/// The number of blocks whose Kate grids are kept in memory between Kate RPC calls.
+	///
+	/// Set it to 0 to disable the cache.
+	#[arg(long, default_value_t = da_runtime::kate::grid_cache::DEFAULT_CACHE_SIZE)]
+	pub kate_grid_cache_size: usize,
 }
 
 fn kate_max_cells_size_upper_bound(s: &str) -> Result<usize, String> {
@@ -211,6 +211,7 @@ pub fn run() -> Result<()> {
 					cli.kate_max_cells_size,
 					cli.kate_rpc_enabled,
 					cli.kate_rpc_metrics_enabled,
+					cli.kate_allow_unfinalized,
 				)?;
 				Ok((cmd.run(client, import_queue), task_manager))
 			})
@@ -228,6 +229,7 @@ pub fn run() -> Result<()> {
 					cli.kate_max_cells_size,
 					cli.kate_rpc_enabled,
 					cli.kate_rpc_metrics_enabled,
+					cli.kate_allow_unfinalized,
 				)?;
 				Ok((cmd.run(client, config.database), task_manager))
 			})
@@ -245,6 +247,7 @@ pub fn run() -> Result<()> {
 					cli.kate_max_cells_size,
 					cli.kate_rpc_enabled,
 					cli.kate_rpc_metrics_enabled,
+					cli.kate_allow_unfinalized,
 				)?;
 				Ok((cmd.run(client, config.chain_spec), task_manager))
 			})
@@ -263,6 +266,7 @@ pub fn run() -> Result<()> {
 					cli.kate_max_cells_size,
 					cli.kate_rpc_enabled,
 					cli.kate_rpc_metrics_enabled,
+					cli.kate_allow_unfinalized,
 				)?;
 				Ok((cmd.run(client, import_queue), task_manager))
 			})
@@ -285,6 +289,7 @@ pub fn run() -> Result<()> {
 					cli.kate_max_cells_size,
 					cli.kate_rpc_enabled,
 					cli.kate_rpc_metrics_enabled,
+					cli.kate_allow_unfinalized,
 				)?;
 				let aux_revert = Box::new(|client: Arc<FullClient>, backend, blocks| {
 					sc_consensus_babe::revert(client.clone(), backend, blocks)?;
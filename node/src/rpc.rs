@@ -102,6 +102,8 @@ pub struct FullDeps<C, P, SC, B> {
 	///
 	/// Should not be used unless unless you know what you're doing.
 	pub kate_rpc_metrics_enabled: bool,
+	/// Allow Kate RPCs to serve blocks which are not finalized yet, if requested.
+	pub kate_allow_unfinalized: bool,
 }
 
 /// Instantiate all Full RPC extensions.
@@ -112,6 +114,7 @@ pub fn create_full<C, P, SC, B>(
 where
 	C: ProvideRuntimeApi<Block>
 		+ sc_client_api::BlockBackend<Block>
+		+ sc_client_api::BlockchainEvents<Block>
 		+ HeaderBackend<Block>
 		+ AuxStore
 		+ HeaderMetadata<Block, Error = BlockChainError>
@@ -155,6 +158,7 @@ where
 		kate_max_cells_size,
 		kate_rpc_enabled,
 		kate_rpc_metrics_enabled,
+		kate_allow_unfinalized,
 	} = deps;
 
 	let BabeDeps {
@@ -230,6 +234,7 @@ where
 		io.merge(KateApiMetricsServer::into_rpc(Kate::<C, Block>::new(
 			client.clone(),
 			kate_max_cells_size,
+			kate_allow_unfinalized,
 		)))?;
 	}
 
@@ -237,6 +242,7 @@ where
 		io.merge(KateApiServer::into_rpc(Kate::<C, Block>::new(
 			client,
 			kate_max_cells_size,
+			kate_allow_unfinalized,
 		)))?;
 	}
 
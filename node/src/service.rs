@@ -26,7 +26,6 @@ use da_runtime::{apis::RuntimeApi, NodeBlock as Block, Runtime};
 use codec::Encode;
 use frame_system_rpc_runtime_api::AccountNonceApi;
 use futures::prelude::*;
-use pallet_transaction_payment::ChargeTransactionPayment;
 use sc_client_api::{Backend, BlockBackend};
 use sc_consensus_babe::{self, SlotProportion};
 pub use sc_executor::NativeElseWasmExecutor;
@@ -135,8 +134,7 @@ pub fn create_extrinsic(
 		frame_system::CheckEra::<Runtime>::from(Era::mortal(period, best_block.saturated_into())),
 		frame_system::CheckNonce::<Runtime>::from(nonce),
 		frame_system::CheckWeight::<Runtime>::new(),
-		ChargeTransactionPayment::<Runtime>::from(tip),
-		da_control::CheckAppId::<Runtime>::from(app_id),
+		da_control::ChargeAppTransactionPayment::<Runtime>::from(tip, app_id),
 	);
 
 	let raw_payload = da_runtime::SignedPayload::from_raw(
@@ -151,7 +149,6 @@ pub fn create_extrinsic(
 			(),
 			(),
 			(),
-			(),
 		),
 	);
 	let signature = raw_payload.using_encoded(|e| sender.sign(e));
@@ -173,6 +170,7 @@ pub fn new_partial(
 	kate_max_cells_size: usize,
 	kate_rpc_enabled: bool,
 	kate_rpc_metrics_enabled: bool,
+	kate_allow_unfinalized: bool,
 ) -> Result<
 	sc_service::PartialComponents<
 		FullClient,
@@ -333,6 +331,7 @@ pub fn new_partial(
 				kate_max_cells_size,
 				kate_rpc_enabled,
 				kate_rpc_metrics_enabled,
+				kate_allow_unfinalized,
 			};
 
 			node_rpc::create_full(deps, rpc_backend.clone()).map_err(Into::into)
@@ -379,6 +378,7 @@ pub fn new_full_base(
 	kate_max_cells_size: usize,
 	kate_rpc_enabled: bool,
 	kate_rpc_metrics_enabled: bool,
+	kate_allow_unfinalized: bool,
 ) -> Result<NewFullBase, ServiceError> {
 	let hwbench = if !disable_hardware_benchmarks {
 		config.database.path().map(|database_path| {
@@ -404,6 +404,7 @@ pub fn new_full_base(
 		kate_max_cells_size,
 		kate_rpc_enabled,
 		kate_rpc_metrics_enabled,
+		kate_allow_unfinalized,
 	)?;
 
 	let shared_voter_state = rpc_setup;
@@ -655,6 +656,7 @@ pub fn new_full_base(
 /// Builds a new service for a full client.
 pub fn new_full(config: Configuration, cli: Cli) -> Result<TaskManager, ServiceError> {
 	let database_path = config.database.path().map(Path::to_path_buf);
+	da_runtime::kate::grid_cache::init(cli.kate_grid_cache_size);
 	let task_manager = new_full_base(
 		config,
 		cli.no_hardware_benchmarks,
@@ -663,6 +665,7 @@ pub fn new_full(config: Configuration, cli: Cli) -> Result<TaskManager, ServiceE
 		cli.kate_max_cells_size,
 		cli.kate_rpc_enabled,
 		cli.kate_rpc_metrics_enabled,
+		cli.kate_allow_unfinalized,
 	)
 	.map(|NewFullBase { task_manager, .. }| task_manager)?;
 
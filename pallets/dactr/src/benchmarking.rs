@@ -9,16 +9,19 @@ use avail_core::{
 };
 use codec::{Decode, Encode};
 use frame_benchmarking::{
-	impl_benchmark_test_suite, v1::BenchmarkError, v2::*, whitelisted_caller,
+	account, impl_benchmark_test_suite, v1::BenchmarkError, v2::*, whitelisted_caller,
+};
+use frame_support::{
+	traits::{Currency, Get, Hooks},
+	BoundedVec,
 };
-use frame_support::traits::Get;
 use frame_system::{
 	limits::BlockLength, native::hosted_header_builder::hosted_header_builder, RawOrigin,
 };
 use scale_info::{StaticTypeInfo, TypeInfo};
 use sp_core::H256;
 use sp_runtime::{
-	traits::{DispatchInfoOf, Dispatchable, SignedExtension},
+	traits::{DispatchInfoOf, Dispatchable, One, SignedExtension},
 	transaction_validity::{TransactionValidity, TransactionValidityError},
 };
 use sp_std::{fmt::Debug, iter::repeat, vec, vec::Vec};
@@ -106,6 +109,23 @@ where
 	B::try_from(raw).expect("Bounded fixed by `len` parameter .qed")
 }
 
+/// Gives `who` enough balance to pay any application key deposit.
+fn fund<T: Config>(who: &T::AccountId) {
+	let balance = Pallet::<T>::app_key_deposit(T::MaxAppKeyLength::get() as usize)
+		.saturating_mul(10u32.into())
+		.saturating_add(T::Currency::minimum_balance());
+	T::Currency::make_free_balance_be(who, balance);
+}
+
+/// Creates an application key of maximum length owned by `owner`.
+fn owned_app_key<T: Config>(owner: T::AccountId) -> AppKeyFor<T> {
+	let key = generate_bounded::<AppKeyFor<T>>(T::MaxAppKeyLength::get());
+	fund::<T>(&owner);
+	Pallet::<T>::create_application_key(RawOrigin::Signed(owner).into(), key.clone())
+		.expect("Key is new and owner is funded .qed");
+	key
+}
+
 fn submit_data_ext<
 	T: frame_system::Config + Send + Sync + pallet::Config + Debug + StaticTypeInfo,
 >(
@@ -164,6 +184,7 @@ mod benchmarks {
 	#[benchmark]
 	fn create_application_key() -> Result<(), BenchmarkError> {
 		let caller = whitelisted_caller::<T::AccountId>();
+		fund::<T>(&caller);
 		let origin = RawOrigin::Signed(caller.clone());
 		let max_key_len = T::MaxAppKeyLength::get();
 		let key = generate_bounded::<AppKeyFor<T>>(max_key_len);
@@ -210,6 +231,8 @@ mod benchmarks {
 			Event::DataSubmitted {
 				who: caller,
 				data_hash,
+				codec: CompressionCodec::None,
+				namespace: None,
 			}
 			.into(),
 		);
@@ -297,6 +320,205 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_app_permissioned() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		let key = owned_app_key::<T>(caller.clone());
+		let id = Pallet::<T>::application_key(&key).unwrap().id;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key, true);
+
+		assert!(PermissionedApps::<T>::contains_key(id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_app_submitter() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		let key = owned_app_key::<T>(caller.clone());
+		let id = Pallet::<T>::application_key(&key).unwrap().id;
+		let submitter = account::<T::AccountId>("submitter", 0, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key, submitter.clone());
+
+		assert!(AppSubmitters::<T>::contains_key(id, submitter));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_app_submitter() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		let key = owned_app_key::<T>(caller.clone());
+		let id = Pallet::<T>::application_key(&key).unwrap().id;
+		let submitter = account::<T::AccountId>("submitter", 0, 0);
+		AppSubmitters::<T>::insert(id, &submitter, ());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key, submitter.clone());
+
+		assert!(!AppSubmitters::<T>::contains_key(id, submitter));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn transfer_application_key_ownership() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		let key = owned_app_key::<T>(caller.clone());
+		let new_owner = account::<T::AccountId>("new_owner", 0, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key.clone(), new_owner.clone());
+
+		assert_eq!(Pallet::<T>::application_key(&key).unwrap().owner, new_owner);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn rename_application_key() -> Result<(), BenchmarkError> {
+		// The deposit is moved from the previous owner to the caller.
+		let depositor: T::AccountId = account("depositor", 0, 0);
+		let caller = whitelisted_caller::<T::AccountId>();
+		let old_key = owned_app_key::<T>(depositor.clone());
+		fund::<T>(&caller);
+		Pallet::<T>::transfer_application_key_ownership(
+			RawOrigin::Signed(depositor).into(),
+			old_key.clone(),
+			caller.clone(),
+		)?;
+		let new_key = AppKeyFor::<T>::try_from(b"Renamed".to_vec()).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), old_key, new_key.clone());
+
+		assert!(Pallet::<T>::application_key(&new_key).is_some());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn retire_application_key() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		let key = owned_app_key::<T>(caller.clone());
+		let id = Pallet::<T>::application_key(&key).unwrap().id;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key);
+
+		assert!(RetiredApps::<T>::contains_key(id));
+		assert!(!AppKeyDeposits::<T>::contains_key(id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_app_fee_modifier() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let value = DispatchFeeModifier {
+			weight_maximum_fee: Some(100),
+			weight_fee_divider: Some(99),
+			weight_fee_multiplier: Some(98),
+		};
+
+		#[extrinsic_call]
+		_(origin, AppId(1), Some(value));
+
+		assert_eq!(AppFeeModifiers::<T>::get(AppId(1)), Some(value));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_app_block_quota() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let quota = AppBlockQuota::Ratio(Perbill::from_percent(25));
+
+		#[extrinsic_call]
+		_(origin, AppId(1), Some(quota));
+
+		assert_eq!(AppBlockQuotas::<T>::get(AppId(1)), Some(quota));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn schedule_block_length_proposal() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let rows = T::MaxBlockRows::get().0;
+		let cols = T::MaxBlockCols::get().0;
+		let delay = T::BlockLengthEnactmentDelay::get().max(1);
+		let enact_at = frame_system::Pallet::<T>::block_number() + delay.into();
+
+		#[extrinsic_call]
+		_(origin, rows, cols, enact_at);
+
+		assert!(PendingBlockLengthProposal::<T>::get().is_some());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn enact_block_length_proposal() -> Result<(), BenchmarkError> {
+		let enact_at = frame_system::Pallet::<T>::block_number() + One::one();
+		PendingBlockLengthProposal::<T>::put(PendingBlockLength {
+			enact_at,
+			rows: T::MaxBlockRows::get(),
+			cols: T::MaxBlockCols::get(),
+		});
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(enact_at);
+		}
+
+		assert!(PendingBlockLengthProposal::<T>::get().is_none());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_block_length_auto_scaling() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let params = BlockLengthAutoScaling {
+			window: T::MaxUtilisationWindow::get(),
+			scale_up_threshold: Perbill::from_percent(80),
+			scale_down_threshold: Perbill::from_percent(20),
+		};
+
+		#[extrinsic_call]
+		_(origin, Some(params));
+
+		assert_eq!(BlockLengthAutoScalingParams::<T>::get(), Some(params));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn auto_adjust_block_length() -> Result<(), BenchmarkError> {
+		let window = T::MaxUtilisationWindow::get();
+		BlockLengthAutoScalingParams::<T>::put(BlockLengthAutoScaling {
+			window,
+			scale_up_threshold: Perbill::from_percent(50),
+			scale_down_threshold: Perbill::from_percent(10),
+		});
+		let samples = vec![Perbill::from_percent(100); window.saturating_sub(1) as usize];
+		BlockUtilisation::<T>::put(BoundedVec::truncate_from(samples));
+
+		let rows = T::MinBlockRows::get();
+		let cols = T::MaxBlockCols::get();
+		let block_length =
+			BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO)
+				.map_err(|_| BenchmarkError::Weightless)?;
+		DynamicBlockLength::<T>::put(block_length);
+		let now = frame_system::Pallet::<T>::block_number();
+
+		#[block]
+		{
+			Pallet::<T>::on_finalize(now);
+			Pallet::<T>::on_initialize(now + One::one());
+		}
+
+		let rows = BlockLengthRows(rows.0 * 2);
+		let event: <T as Config>::RuntimeEvent =
+			Event::BlockLengthAutoAdjusted { rows, cols }.into();
+		frame_system::Pallet::<T>::assert_has_event(event.into());
+		Ok(())
+	}
+
 	#[benchmark(extra)]
 	fn commitment_builder_64(
 		i: Linear<32, { T::MaxBlockRows::get().0 }>,
@@ -0,0 +1,299 @@
+use crate::{extensions::check_app_id::CheckAppId, Call as DACall, Config as DAConfig};
+use avail_core::{traits::GetAppId, AppId};
+
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::{DispatchInfo, DispatchResult, PostDispatchInfo},
+	traits::IsSubType,
+};
+use frame_system::Config as SystemConfig;
+use pallet_transaction_payment::{
+	BalanceOf as FeeBalanceOf, ChargeTransactionPayment, Config as TransactionPaymentConfig,
+};
+use pallet_utility::{Call as UtilityCall, Config as UtilityConfig};
+use pallet_vector::{Call as VectorCall, Config as VectorConfig};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SignedExtension},
+	transaction_validity::{TransactionValidity, TransactionValidityError, ValidTransaction},
+	FixedPointOperand,
+};
+use sp_std::{
+	fmt::{self, Debug, Formatter},
+	vec::Vec,
+};
+
+/// Charges the transaction fee and checks the Application Id.
+///
+/// It wraps `ChargeTransactionPayment` and `CheckAppId`, so the fee of a data submission is
+/// computed, withdrawn, corrected and reported with the fee modifier of its application, if it
+/// has one. Its encoding and metadata are the ones of both extensions in sequence.
+///
+/// # Batches
+///
+/// Data submissions are not allowed inside `Utility::batch/batch_all/force_batch` (see
+/// `CheckBatchTransactions`), so the modifiers of applications apply only to direct submissions.
+///
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeAppTransactionPayment<
+	T: DAConfig + UtilityConfig + TransactionPaymentConfig + Send + Sync,
+>(pub ChargeTransactionPayment<T>, pub CheckAppId<T>);
+
+impl<T> ChargeAppTransactionPayment<T>
+where
+	T: DAConfig + VectorConfig + UtilityConfig + TransactionPaymentConfig + Send + Sync,
+	<T as SystemConfig>::RuntimeCall: IsSubType<DACall<T>>
+		+ IsSubType<UtilityCall<T>>
+		+ IsSubType<VectorCall<T>>
+		+ Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	FeeBalanceOf<T>: Send + Sync + From<u64>,
+	[u8; 32]: From<<T as frame_system::Config>::AccountId>,
+{
+	/// utility constructor. Used only in client/factory code.
+	pub fn from(tip: FeeBalanceOf<T>, app_id: AppId) -> Self {
+		Self(
+			ChargeTransactionPayment::from(tip),
+			CheckAppId::from(app_id),
+		)
+	}
+}
+
+impl<T> Debug for ChargeAppTransactionPayment<T>
+where
+	T: DAConfig + UtilityConfig + VectorConfig + TransactionPaymentConfig + Send + Sync,
+{
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "ChargeAppTransactionPayment<{:?}, {:?}>", self.0, self.1)
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut Formatter) -> fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T> SignedExtension for ChargeAppTransactionPayment<T>
+where
+	T: DAConfig + VectorConfig + UtilityConfig + TransactionPaymentConfig + Send + Sync,
+	<T as SystemConfig>::RuntimeCall: IsSubType<DACall<T>>
+		+ IsSubType<UtilityCall<T>>
+		+ IsSubType<VectorCall<T>>
+		+ Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	FeeBalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+	[u8; 32]: From<<T as frame_system::Config>::AccountId>,
+{
+	type AccountId = T::AccountId;
+	type AdditionalSigned = ();
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	/// The `Pre` of `ChargeTransactionPayment`, and the dispatch info its fee was computed with,
+	/// if it is not the one of the call.
+	type Pre = (
+		<ChargeTransactionPayment<T> as SignedExtension>::Pre,
+		Option<DispatchInfo>,
+	);
+
+	const IDENTIFIER: &'static str = "ChargeAppTransactionPayment";
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		let app_info = self.1.app_dispatch_info(call, info);
+		let fee_info = app_info.as_ref().unwrap_or(info);
+		let valid = ValidTransaction::default()
+			.combine_with(self.0.validate(who, call, fee_info, len)?)
+			.combine_with(self.1.validate(who, call, info, len)?);
+		Ok(valid)
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		let app_info = self.1.app_dispatch_info(call, info);
+		let fee_info = app_info.as_ref().unwrap_or(info);
+		let pre = self.0.pre_dispatch(who, call, fee_info, len)?;
+		self.1.pre_dispatch(who, call, info, len)?;
+		Ok((pre, app_info))
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		let (pre, app_info) = match pre {
+			Some((pre, app_info)) => (Some(pre), app_info),
+			None => (None, None),
+		};
+		let check_pre = pre.as_ref().map(|_| ());
+		let fee_info = app_info.as_ref().unwrap_or(info);
+		ChargeTransactionPayment::<T>::post_dispatch(pre, fee_info, post_info, len, result)?;
+		CheckAppId::<T>::post_dispatch(check_pre, info, post_info, len, result)
+	}
+
+	fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+		Ok(())
+	}
+
+	fn metadata() -> Vec<sp_runtime::traits::SignedExtensionMetadata> {
+		let mut metadata = ChargeTransactionPayment::<T>::metadata();
+		metadata.append(&mut CheckAppId::<T>::metadata());
+		metadata
+	}
+}
+
+impl<T> GetAppId for ChargeAppTransactionPayment<T>
+where
+	T: DAConfig + UtilityConfig + VectorConfig + TransactionPaymentConfig + Send + Sync,
+{
+	#[inline]
+	fn app_id(&self) -> AppId {
+		self.1.app_id()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use avail_core::InvalidTransactionCustomId::UnexpectedSubmitDataCall;
+	use frame_support::{
+		assert_ok,
+		dispatch::{DispatchFeeModifier, GetDispatchInfo},
+		traits::fungible::{Inspect, Mutate},
+	};
+	use pallet_utility::pallet::Call as UtilityCall;
+	use sp_runtime::transaction_validity::InvalidTransaction;
+
+	use super::*;
+	use crate::extensions::extensions_mock::{
+		new_test_ext, u8_to_account_id, Balances, RuntimeCall, RuntimeEvent, System, Test,
+		TransactionPayment,
+	};
+	use crate::pallet::{AppFeeModifiers, Call as DACall};
+
+	fn submit_data_call() -> RuntimeCall {
+		RuntimeCall::DataAvailability(DACall::submit_data {
+			data: vec![].try_into().unwrap(),
+		})
+	}
+
+	#[test]
+	fn app_fee_modifier_test() {
+		let call = submit_data_call();
+		let info = call.get_dispatch_info();
+		let post_info = PostDispatchInfo::default();
+		let len = 100;
+		let alice = u8_to_account_id(1);
+		let divider = DispatchFeeModifier {
+			weight_maximum_fee: None,
+			weight_fee_divider: Some(2),
+			weight_fee_multiplier: None,
+		};
+		let app_info = DispatchInfo {
+			fee_modifier: divider,
+			..info
+		};
+
+		new_test_ext().execute_with(|| {
+			Balances::set_balance(&alice, 1 << 50);
+			AppFeeModifiers::<Test>::insert(AppId(1), divider);
+
+			let charge = |app_id: u32| {
+				let before = Balances::balance(&alice);
+				let pre = ChargeAppTransactionPayment::<Test>::from(0, AppId(app_id))
+					.pre_dispatch(&alice, &call, &info, len)
+					.unwrap();
+				ChargeAppTransactionPayment::<Test>::post_dispatch(
+					Some(pre),
+					&info,
+					&post_info,
+					len,
+					&Ok(()),
+				)
+				.unwrap();
+				before - Balances::balance(&alice)
+			};
+
+			let fee = TransactionPayment::compute_fee(len as u32, &info, 0);
+			let app_fee = TransactionPayment::compute_fee(len as u32, &app_info, 0);
+			assert!(app_fee < fee);
+			assert_eq!(charge(0), fee);
+			assert_eq!(charge(1), app_fee);
+
+			// The reported fee is the one charged.
+			let event = RuntimeEvent::TransactionPayment(
+				pallet_transaction_payment::Event::TransactionFeePaid {
+					who: alice.clone(),
+					actual_fee: app_fee,
+					tip: 0,
+				},
+			);
+			System::assert_last_event(event);
+		});
+	}
+
+	#[test]
+	fn app_fee_surcharge_test() {
+		let call = submit_data_call();
+		let info = call.get_dispatch_info();
+		let len = 100;
+		let alice = u8_to_account_id(1);
+		let multiplier = DispatchFeeModifier {
+			weight_maximum_fee: None,
+			weight_fee_divider: None,
+			weight_fee_multiplier: Some(3),
+		};
+
+		new_test_ext().execute_with(|| {
+			AppFeeModifiers::<Test>::insert(AppId(1), multiplier);
+			let fee = TransactionPayment::compute_fee(len as u32, &info, 0);
+			Balances::set_balance(&alice, fee + 10);
+
+			// The fee of the global modifier is affordable, but not the one of the app.
+			assert_ok!(ChargeAppTransactionPayment::<Test>::from(0, AppId(0))
+				.validate(&alice, &call, &info, len));
+			assert_eq!(
+				ChargeAppTransactionPayment::<Test>::from(0, AppId(1))
+					.validate(&alice, &call, &info, len),
+				Err(InvalidTransaction::Payment.into())
+			);
+		});
+	}
+
+	#[test]
+	fn batch_does_not_use_app_fee_modifier() {
+		let call = RuntimeCall::Utility(UtilityCall::batch_all {
+			calls: vec![submit_data_call()],
+		});
+		let info = call.get_dispatch_info();
+		let len = 100;
+		let alice = u8_to_account_id(1);
+		let divider = DispatchFeeModifier {
+			weight_maximum_fee: None,
+			weight_fee_divider: Some(2),
+			weight_fee_multiplier: None,
+		};
+
+		new_test_ext().execute_with(|| {
+			Balances::set_balance(&alice, 1 << 50);
+			AppFeeModifiers::<Test>::insert(AppId(1), divider);
+
+			let charge = ChargeAppTransactionPayment::<Test>::from(0, AppId(1));
+			assert_eq!(charge.1.app_dispatch_info(&call, &info), None);
+			assert_eq!(
+				charge.validate(&alice, &call, &info, len),
+				Err(InvalidTransaction::Custom(UnexpectedSubmitDataCall as u8).into())
+			);
+		});
+	}
+}
@@ -1,9 +1,12 @@
-use super::MAX_ITERATIONS;
-use crate::{Call as DACall, CheckBatchTransactions, Config as DAConfig, Pallet, LOG_TARGET};
+use super::{DaInvalidTransactionCustomId, MAX_ITERATIONS};
+use crate::{
+	AppFeeModifiers, Call as DACall, CheckBatchTransactions, Config as DAConfig, Pallet, LOG_TARGET,
+};
 use avail_core::{traits::GetAppId, AppId, InvalidTransactionCustomId};
 
 use codec::{Decode, Encode};
 use frame_support::{
+	dispatch::DispatchInfo,
 	ensure,
 	traits::{IsSubType, IsType},
 };
@@ -28,7 +31,13 @@ use sp_std::{
 ///
 /// # Transaction Validity
 ///
-/// Only registered application can be used by transactions.
+/// Only registered application can be used by transactions, and only the allowed submitters of a
+/// permissioned application can submit data to it.
+///
+/// # Fees
+///
+/// Data submissions to an application with a specific fee modifier are charged with it by
+/// `ChargeAppTransactionPayment`, which wraps this extension.
 ///
 #[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
 #[scale_info(skip_type_params(T))]
@@ -51,21 +60,25 @@ where
 
 	/// It validates that `AppId` is correct and already registered for the call and potential nested calls.
 	/// Transaction validation:
-	///  - `DataAvailability::submit_data(..)` extrinsic can use `AppId != 0`.
+	///  - `DataAvailability::submit_data(..)` and `DataAvailability::submit_data_with_options(..)`
+	///  extrinsics can use `AppId != 0`, if it is not retired
+	///  and `who` is allowed to submit data to it.
 	///  - `Utility::batch/batch_all/force_batch(..)` extrinsic can use `AppId != 0` If the wrapped calls are ALL `DataAvailability::submit_data(..)`.
 	///  - Any other call must use `AppId == 0`.
 	///  - It also ensures that Kate's evaluation grid can be generated during the header
-	///  production.
+	///  production, and that each `AppId` does not exceed its block space quota.
 	pub fn do_validate(
 		&self,
+		who: &T::AccountId,
 		call: &<T as SystemConfig>::RuntimeCall,
 		len: usize,
 	) -> TransactionValidity {
-		self.ensure_valid_app_id(call)?;
-		if let Some(DACall::<T>::submit_data { .. }) = call.is_sub_type() {
-			let all_extrinsics_len = self
-				.next_all_extrinsics_len(len)
-				.ok_or(InvalidTransaction::ExhaustsResources)?;
+		self.ensure_valid_app_id(who, call)?;
+		if let Some(
+			DACall::<T>::submit_data { .. } | DACall::<T>::submit_data_with_options { .. },
+		) = call.is_sub_type()
+		{
+			let all_extrinsics_len = self.next_all_extrinsics_len(len)?;
 			AllExtrinsicsLen::<T>::put(all_extrinsics_len);
 		}
 
@@ -73,34 +86,83 @@ where
 		Ok(ValidTransaction::default())
 	}
 
-	fn next_all_extrinsics_len(&self, len: usize) -> Option<ExtrinsicLenOf<T>> {
+	/// Returns the dispatch info of `call` with the fee modifier of the application, if `call`
+	/// submits data and the application has a specific modifier.
+	///
+	/// Batches never get it, as they cannot contain data submissions.
+	pub fn app_dispatch_info(
+		&self,
+		call: &<T as SystemConfig>::RuntimeCall,
+		info: &DispatchInfo,
+	) -> Option<DispatchInfo> {
+		if let Some(
+			DACall::<T>::submit_data { .. } | DACall::<T>::submit_data_with_options { .. },
+		) = call.is_sub_type()
+		{
+			let fee_modifier = AppFeeModifiers::<T>::get(self.app_id())?;
+			return Some(DispatchInfo {
+				fee_modifier,
+				..*info
+			});
+		}
+		None
+	}
+
+	fn next_all_extrinsics_len(
+		&self,
+		len: usize,
+	) -> Result<ExtrinsicLenOf<T>, TransactionValidityError> {
+		let exhausted = InvalidTransaction::ExhaustsResources;
 		let app_id = self.app_id();
-		let len = u32::try_from(len).ok()?;
+		let len = u32::try_from(len).map_err(|_| exhausted)?;
 
 		// Get maximum padded length of current block length.
 		let curr_len = DynamicBlockLength::<T>::get();
-		let max_scalars = curr_len.rows.0.checked_mul(curr_len.cols.0)?;
+		let max_scalars = curr_len
+			.rows
+			.0
+			.checked_mul(curr_len.cols.0)
+			.ok_or(exhausted)?;
 
 		// Update extrinsics length info.
 		let mut all_extrinsics_len = AllExtrinsicsLen::<T>::get().unwrap_or_default();
-		let _ = all_extrinsics_len.add_padded(app_id, len)?;
+		let app_scalars = all_extrinsics_len
+			.add_padded(app_id, len)
+			.ok_or(exhausted)?;
+
+		// Check the block space quota of the application.
+		if let Some(quota) = <Pallet<T>>::app_quota_scalars(app_id, max_scalars) {
+			if app_scalars > quota {
+				log::warn!(
+					target: LOG_TARGET,
+					"Block space quota of {app_id} (max {quota} scalars) is exhausted, requested {app_scalars}");
+
+				// Block authors keep the Tx in the pool, as it could fit into the quota of next
+				// blocks.
+				return Err(InvalidTransaction::Custom(
+					DaInvalidTransactionCustomId::AppQuotaExceeded as u8,
+				)
+				.into());
+			}
+		}
 
 		// Calculate total padded length
-		let total_scalars = all_extrinsics_len.total_num_scalars()?;
+		let total_scalars = all_extrinsics_len.total_num_scalars().ok_or(exhausted)?;
 
 		if total_scalars < max_scalars {
-			Some(all_extrinsics_len)
+			Ok(all_extrinsics_len)
 		} else {
 			log::warn!(
 				target: LOG_TARGET,
 				"Padded block length (max {max_scalars} scalars) is exhausted, requested {total_scalars}");
 
-			None
+			Err(exhausted.into())
 		}
 	}
 
 	fn ensure_valid_app_id(
 		&self,
+		who: &T::AccountId,
 		call: &<T as SystemConfig>::RuntimeCall,
 	) -> Result<(), TransactionValidityError> {
 		if self.app_id() == AppId(0) {
@@ -114,13 +176,26 @@ where
 		let mut iterations = 0;
 
 		while let Some(call) = stack.pop() {
-			if let Some(DACall::<T>::submit_data { .. }) = call.is_sub_type() {
+			if let Some(
+				DACall::<T>::submit_data { .. } | DACall::<T>::submit_data_with_options { .. },
+			) = call.is_sub_type()
+			{
 				let next_app_id =
 					maybe_next_app_id.get_or_insert_with(<Pallet<T>>::peek_next_application_id);
 				ensure!(
 					self.app_id() < *next_app_id,
 					InvalidTransaction::Custom(InvalidTransactionCustomId::InvalidAppId as u8)
 				);
+				ensure!(
+					!<Pallet<T>>::is_retired(self.app_id()),
+					InvalidTransaction::Custom(DaInvalidTransactionCustomId::RetiredAppId as u8)
+				);
+				ensure!(
+					<Pallet<T>>::is_allowed_submitter(self.app_id(), who),
+					InvalidTransaction::Custom(
+						DaInvalidTransactionCustomId::ForbiddenSubmitter as u8
+					)
+				);
 			} else {
 				match call.is_sub_type() {
 					Some(UtilityCall::<T>::batch { calls })
@@ -188,22 +263,22 @@ where
 
 	fn validate(
 		&self,
-		_who: &Self::AccountId,
+		who: &Self::AccountId,
 		call: &Self::Call,
 		_info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> TransactionValidity {
-		self.do_validate(call, len)
+		self.do_validate(who, call, len)
 	}
 
 	fn pre_dispatch(
 		self,
-		_who: &Self::AccountId,
+		who: &Self::AccountId,
 		call: &Self::Call,
 		_info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> Result<Self::Pre, TransactionValidityError> {
-		self.do_validate(call, len)?;
+		self.do_validate(who, call, len)?;
 		Ok(())
 	}
 
@@ -233,8 +308,10 @@ mod tests {
 	use test_case::test_case;
 
 	use super::*;
-	use crate::extensions::extensions_mock::{new_test_ext, RuntimeCall, Test};
-	use crate::pallet::Call as DACall;
+	use crate::extensions::extensions_mock::{new_test_ext, u8_to_account_id, RuntimeCall, Test};
+	use crate::pallet::{
+		AppBlockQuota, AppBlockQuotas, AppSubmitters, Call as DACall, PermissionedApps, RetiredApps,
+	};
 
 	fn remark_call() -> RuntimeCall {
 		RuntimeCall::System(SysCall::remark { remark: vec![] })
@@ -260,6 +337,78 @@ mod tests {
 		let extrinsic =
 			AppUncheckedExtrinsic::<u32, RuntimeCall, (), ()>::new_unsigned(call.clone());
 		let len = extrinsic.encoded_size();
-		new_test_ext().execute_with(|| CheckAppId::<Test>::from(AppId(id)).do_validate(&call, len))
+		new_test_ext().execute_with(|| {
+			CheckAppId::<Test>::from(AppId(id)).do_validate(&u8_to_account_id(1), &call, len)
+		})
+	}
+
+	#[test_case(1, 1 => Ok(ValidTransaction::default()); "Allowed submitter can submit data to a permissioned AppId" )]
+	#[test_case(1, 2 => Err(TransactionValidityError::Invalid(InvalidTransaction::Custom(DaInvalidTransactionCustomId::ForbiddenSubmitter as u8))); "Other submitters are blocked on a permissioned AppId" )]
+	#[test_case(0, 2 => Ok(ValidTransaction::default()); "AppId 0 is always open" )]
+	fn permissioned_app_test(id: u32, who: u8) -> TransactionValidity {
+		let call = submit_data_call();
+		let extrinsic =
+			AppUncheckedExtrinsic::<u32, RuntimeCall, (), ()>::new_unsigned(call.clone());
+		let len = extrinsic.encoded_size();
+		new_test_ext().execute_with(|| {
+			PermissionedApps::<Test>::insert(AppId(id), ());
+			AppSubmitters::<Test>::insert(AppId(id), u8_to_account_id(1), ());
+			CheckAppId::<Test>::from(AppId(id)).do_validate(&u8_to_account_id(who), &call, len)
+		})
+	}
+
+	#[test]
+	fn retired_app_test() {
+		let call = submit_data_call();
+		let extrinsic =
+			AppUncheckedExtrinsic::<u32, RuntimeCall, (), ()>::new_unsigned(call.clone());
+		let len = extrinsic.encoded_size();
+		let validity = new_test_ext().execute_with(|| {
+			RetiredApps::<Test>::insert(AppId(1), ());
+			CheckAppId::<Test>::from(AppId(1)).do_validate(&u8_to_account_id(1), &call, len)
+		});
+		assert_eq!(
+			validity,
+			Err(TransactionValidityError::Invalid(
+				InvalidTransaction::Custom(DaInvalidTransactionCustomId::RetiredAppId as u8)
+			))
+		);
+	}
+
+	#[test]
+	fn app_block_quota_test() {
+		let call = RuntimeCall::DataAvailability(DACall::submit_data {
+			data: vec![0u8; 20].try_into().unwrap(),
+		});
+		let extrinsic =
+			AppUncheckedExtrinsic::<u32, RuntimeCall, (), ()>::new_unsigned(call.clone());
+		let len = extrinsic.encoded_size();
+		let alice = u8_to_account_id(1);
+		let quota_exceeded = Err(TransactionValidityError::Invalid(
+			InvalidTransaction::Custom(DaInvalidTransactionCustomId::AppQuotaExceeded as u8),
+		));
+
+		new_test_ext().execute_with(|| {
+			let check = CheckAppId::<Test>::from(AppId(1));
+
+			// The Tx does not fit into an empty quota.
+			AppBlockQuotas::<Test>::insert(AppId(1), AppBlockQuota::Scalars(0));
+			assert_eq!(check.do_validate(&alice, &call, len), quota_exceeded);
+
+			// The quota fits one Tx, and the next one has to wait for the next block.
+			AppBlockQuotas::<Test>::insert(AppId(1), AppBlockQuota::Scalars(1));
+			assert_eq!(
+				check.do_validate(&alice, &call, len),
+				Ok(ValidTransaction::default())
+			);
+			assert_eq!(check.do_validate(&alice, &call, len), quota_exceeded);
+
+			// Other applications are not affected.
+			let other = CheckAppId::<Test>::from(AppId(0));
+			assert_eq!(
+				other.do_validate(&alice, &call, len),
+				Ok(ValidTransaction::default())
+			);
+		});
 	}
 }
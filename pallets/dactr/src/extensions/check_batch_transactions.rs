@@ -29,7 +29,10 @@ where
 	[u8; 32]: From<<T as frame_system::Config>::AccountId>,
 {
 	pub fn is_submit_data_call(&self) -> bool {
-		matches!(self.0.is_sub_type(), Some(DACall::<T>::submit_data { .. }))
+		matches!(
+			self.0.is_sub_type(),
+			Some(DACall::<T>::submit_data { .. } | DACall::<T>::submit_data_with_options { .. })
+		)
 	}
 
 	pub fn is_send_message_call(&self) -> bool {
@@ -138,7 +141,7 @@ mod tests {
 	use super::*;
 	use crate::pallet::Call as DACall;
 	use crate::{
-		extensions::extensions_mock::{new_test_ext, RuntimeCall, Test},
+		extensions::extensions_mock::{new_test_ext, u8_to_account_id, RuntimeCall, Test},
 		CheckAppId,
 	};
 
@@ -186,7 +189,9 @@ mod tests {
 		let extrinsic =
 			AppUncheckedExtrinsic::<u32, RuntimeCall, (), ()>::new_unsigned(call.clone());
 		let len = extrinsic.encoded_size();
-		new_test_ext().execute_with(|| CheckAppId::<Test>::from(AppId(0)).do_validate(&call, len))
+		new_test_ext().execute_with(|| {
+			CheckAppId::<Test>::from(AppId(0)).do_validate(&u8_to_account_id(1), &call, len)
+		})
 	}
 
 	#[test]
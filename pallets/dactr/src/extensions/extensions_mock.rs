@@ -1,10 +1,14 @@
 #![cfg(test)]
 
 use frame_support::weights::WeightToFee;
-use frame_support::{derive_impl, weights::IdentityFee};
+use frame_support::{
+	derive_impl,
+	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64},
+	weights::IdentityFee,
+};
 use frame_system::{
 	mocking::MockUncheckedExtrinsic, native::hosted_header_builder::da::HeaderExtensionBuilder,
-	test_utils::TestRandomness,
+	test_utils::TestRandomness, EnsureRoot, EnsureSigned,
 };
 use pallet_transaction_payment::FungibleAdapter;
 use sp_runtime::{AccountId32, BuildStorage};
@@ -23,6 +27,7 @@ frame_support::construct_runtime!(
 		System: frame_system,
 		Utility: pallet_utility,
 		Balances: pallet_balances,
+		Assets: pallet_assets,
 		TransactionPayment: pallet_transaction_payment,
 		DataAvailability: da_control,
 		Vector: pallet_vector,
@@ -71,18 +76,46 @@ impl pallet_utility::Config for Test {
 }
 
 #[derive_impl(da_control::config_preludes::TestDefaultConfig)]
-impl da_control::Config for Test {}
+impl da_control::Config for Test {
+	type AppKeyByteDeposit = ConstU64<1>;
+	type AppKeyDeposit = ConstU64<10>;
+	type Currency = Balances;
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId32>>;
+	type ForceOrigin = EnsureRoot<AccountId32>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type ApprovalDeposit = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	type RemoveItemsLimit = ConstU32<1000>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
 
 #[derive_impl(pallet_vector::config_preludes::TestDefaultConfig as pallet_vector::DefaultConfig)]
 impl pallet_vector::Config for Test {
 	type TimeProvider = Timestamp;
 	type Currency = Balances;
+	type Assets = Assets;
 }
 
 #[derive_impl(pallet_timestamp::config_preludes::TestDefaultConfig as pallet_timestamp::DefaultConfig)]
 impl pallet_timestamp::Config for Test {}
 
-fn u8_to_account_id(value: u8) -> AccountId32 {
+pub(crate) fn u8_to_account_id(value: u8) -> AccountId32 {
 	let mut account = [0u8; 32];
 	account[0] = value;
 
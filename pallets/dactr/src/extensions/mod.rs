@@ -1,5 +1,20 @@
+pub mod charge_app_transaction_payment;
 pub mod check_app_id;
 pub mod check_batch_transactions;
 pub mod extensions_mock;
 
 const MAX_ITERATIONS: usize = 2;
+
+/// Custom codes of `InvalidTransaction::Custom` raised by `da_control`, in a range disjoint from
+/// `avail_core::InvalidTransactionCustomId`.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DaInvalidTransactionCustomId {
+	/// The signer is not in the allow-list of the permissioned `AppId`.
+	ForbiddenSubmitter = 200,
+	/// The `AppId` was retired by its owner.
+	RetiredAppId = 201,
+	/// The Tx does not fit into the remaining block space quota of its `AppId`. Block authors
+	/// keep it in the pool, so it can be included once the quota is available again.
+	AppQuotaExceeded = 202,
+}
@@ -8,32 +8,44 @@ use avail_core::{
 };
 use codec::{Compact, CompactLen as _};
 use frame_support::weights::constants::ExtrinsicBaseWeight;
-use frame_support::{dispatch::DispatchClass, traits::Get, weights::Weight};
-use frame_system::{limits::BlockLength, pallet::DynamicBlockLength};
+use frame_support::{
+	dispatch::{DispatchClass, DispatchResult},
+	ensure,
+	traits::{Currency, Get, ReservableCurrency},
+	weights::Weight,
+};
+use frame_system::{limits::BlockLength, pallet::DynamicBlockLength, AllExtrinsicsLen};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use sp_arithmetic::traits::{CheckedAdd, One, SaturatedConversion};
+use sp_arithmetic::traits::{CheckedAdd, One, SaturatedConversion, Saturating};
 use sp_core::H256;
 use sp_io::hashing::blake2_256;
 use sp_runtime::Perbill;
 use sp_std::{mem::replace, vec, vec::Vec};
 
 pub use crate::{pallet::*, weights::WeightInfo};
+pub use avail_base::header_extension::Namespace;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 mod extensions;
+pub mod migrations;
 #[cfg(feature = "std")]
 pub mod mock;
 #[cfg(test)]
 mod tests;
+pub use extensions::charge_app_transaction_payment::ChargeAppTransactionPayment;
 pub use extensions::check_app_id::CheckAppId;
 pub use extensions::check_batch_transactions::CheckBatchTransactions;
+pub use extensions::DaInvalidTransactionCustomId;
 use frame_support::dispatch::DispatchFeeModifier;
 pub mod weights;
 
 pub const LOG_TARGET: &str = "runtime::da_control";
 
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{pallet_prelude::*, DefaultNoBound};
@@ -55,6 +67,66 @@ pub mod pallet {
 
 	pub type AppKeyInfoFor<T> = AppKeyInfo<<T as frame_system::Config>::AccountId>;
 
+	/// Block space which one application can use for its data.
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[derive(Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+	pub enum AppBlockQuota {
+		/// Maximum number of padded scalars.
+		Scalars(u32),
+		/// Maximum share of the padded scalars of the current block length.
+		Ratio(Perbill),
+	}
+
+	/// Compression codec applied by the submitter to the data of a blob.
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[derive(
+		Clone, Copy, Default, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug, MaxEncodedLen,
+	)]
+	pub enum CompressionCodec {
+		/// Data is submitted as is.
+		#[default]
+		#[codec(index = 0)]
+		None,
+		/// Data is compressed using Zstandard.
+		#[codec(index = 1)]
+		Zstd,
+		/// Data is compressed using Brotli.
+		#[codec(index = 2)]
+		Brotli,
+	}
+
+	/// Block dimensions which will be applied at block `enact_at`.
+	#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+	pub struct PendingBlockLength<BlockNumber> {
+		pub enact_at: BlockNumber,
+		pub rows: BlockLengthRows,
+		pub cols: BlockLengthColumns,
+	}
+
+	pub type PendingBlockLengthFor<T> = PendingBlockLength<BlockNumberFor<T>>;
+
+	/// Parameters of the automatic scaling of block rows based on the DA demand.
+	///
+	/// Rows are doubled when the average utilisation of the block space over the last `window`
+	/// blocks is above `scale_up_threshold`, and halved when it is below `scale_down_threshold`.
+	/// The window starts over after each adjustment.
+	#[derive(Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+	pub struct BlockLengthAutoScaling {
+		pub window: u32,
+		pub scale_up_threshold: Perbill,
+		pub scale_down_threshold: Perbill,
+	}
+
+	impl AppBlockQuota {
+		/// Returns the maximum number of padded scalars out of `block_scalars`.
+		pub fn max_scalars(&self, block_scalars: u32) -> u32 {
+			match self {
+				Self::Scalars(scalars) => *scalars,
+				Self::Ratio(ratio) => ratio.mul_floor(block_scalars),
+			}
+		}
+	}
+
 	/// Default implementations of [`DefaultConfig`], which can be used to implement [`Config`].
 	pub mod config_preludes {
 		use super::*;
@@ -76,15 +148,19 @@ pub mod pallet {
 			pub const MaxBlockCols: BlockLengthColumns = BlockLengthColumns(256);
 			pub const MaxAppKeyLength: u32 = 32;
 			pub const MaxAppDataLength: u32 = 524_288; // 512 Kb
+			pub const MaxUtilisationWindow: u32 = 64;
+			pub const BlockLengthEnactmentDelay: u32 = 0;
 		}
 
 		#[frame_support::register_default_impl(TestDefaultConfig)]
 		impl DefaultConfig for TestDefaultConfig {
 			type BlockLenProposalId = u32;
+			type BlockLengthEnactmentDelay = BlockLengthEnactmentDelay;
 			type MaxAppDataLength = MaxAppDataLength;
 			type MaxAppKeyLength = MaxAppKeyLength;
 			type MaxBlockCols = MaxBlockCols;
 			type MaxBlockRows = MaxBlockRows;
+			type MaxUtilisationWindow = MaxUtilisationWindow;
 			type MinBlockCols = MinBlockCols;
 			type MinBlockRows = MinBlockRows;
 			type WeightInfo = ();
@@ -126,11 +202,39 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxBlockCols: Get<BlockLengthColumns>;
 
+		/// Maximum number of blocks observed by the automatic block length scaling.
+		#[pallet::constant]
+		type MaxUtilisationWindow: Get<u32>;
+
+		/// Minimum number of blocks between a block length proposal and its enactment.
+		///
+		/// If it is zero, proposals of `submit_block_length_proposal` are applied immediately.
+		#[pallet::constant]
+		type BlockLengthEnactmentDelay: Get<u32>;
+
 		/// Weights for this pallet.
 		type WeightInfo: weights::WeightInfo;
+
+		/// Currency used to reserve the deposit of application keys.
+		#[pallet::no_default]
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The base deposit reserved when an application key is created.
+		#[pallet::constant]
+		#[pallet::no_default]
+		type AppKeyDeposit: Get<BalanceOf<Self>>;
+
+		/// The deposit reserved per byte of the application key.
+		#[pallet::constant]
+		#[pallet::no_default]
+		type AppKeyByteDeposit: Get<BalanceOf<Self>>;
 	}
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Last application ID
@@ -143,13 +247,59 @@ pub mod pallet {
 	#[pallet::getter(fn application_key)]
 	pub type AppKeys<T: Config> = StorageMap<_, Blake2_128Concat, AppKeyFor<T>, AppKeyInfoFor<T>>;
 
+	/// Reverse index of `AppKeys`, from application ID to its key.
+	#[pallet::storage]
+	pub type AppIdToKey<T: Config> = StorageMap<_, Twox64Concat, AppId, AppKeyFor<T>>;
+
 	/// Store data fee modifier for submit_data call.
 	#[pallet::storage]
 	pub type SubmitDataFeeModifier<T: Config> = StorageValue<_, DispatchFeeModifier, ValueQuery>;
 
+	/// Fee modifiers of `submit_data` for specific applications, which replace
+	/// `SubmitDataFeeModifier` for them. They are charged by `ChargeAppTransactionPayment`.
+	#[pallet::storage]
+	pub type AppFeeModifiers<T: Config> = StorageMap<_, Twox64Concat, AppId, DispatchFeeModifier>;
+
+	/// Block space quotas of specific applications.
+	#[pallet::storage]
+	pub type AppBlockQuotas<T: Config> = StorageMap<_, Twox64Concat, AppId, AppBlockQuota>;
+
+	/// Block length proposal waiting for its enactment block.
+	#[pallet::storage]
+	pub type PendingBlockLengthProposal<T: Config> = StorageValue<_, PendingBlockLengthFor<T>>;
+
+	/// Parameters of the automatic block length scaling, which is disabled if unset.
+	#[pallet::storage]
+	pub type BlockLengthAutoScalingParams<T: Config> = StorageValue<_, BlockLengthAutoScaling>;
+
+	/// Utilisation of the block space by the latest blocks, oldest first.
+	#[pallet::storage]
+	pub type BlockUtilisation<T: Config> =
+		StorageValue<_, BoundedVec<Perbill, T::MaxUtilisationWindow>, ValueQuery>;
+
+	/// Applications whose data can only be submitted by the accounts in `AppSubmitters`.
+	#[pallet::storage]
+	pub type PermissionedApps<T: Config> = StorageMap<_, Twox64Concat, AppId, ()>;
+
+	/// Accounts allowed to submit data to each permissioned application.
+	#[pallet::storage]
+	pub type AppSubmitters<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, AppId, Blake2_128Concat, T::AccountId, ()>;
+
+	/// The account which paid the deposit of each application, and the reserved amount.
+	#[pallet::storage]
+	pub type AppKeyDeposits<T: Config> =
+		StorageMap<_, Twox64Concat, AppId, (T::AccountId, BalanceOf<T>)>;
+
+	/// Applications retired by their owners, which do not accept data submissions anymore.
+	#[pallet::storage]
+	pub type RetiredApps<T: Config> = StorageMap<_, Twox64Concat, AppId, ()>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Creates an application key if `key` does not exist yet.
+		///
+		/// The deposit of the key is reserved from the caller, and returned when it is retired.
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::create_application_key())]
 		pub fn create_application_key(
@@ -169,6 +319,11 @@ pub mod pallet {
 
 				Ok(id)
 			})?;
+			AppIdToKey::<T>::insert(id, &key);
+
+			let deposit = Self::app_key_deposit(key.len());
+			T::Currency::reserve(&owner, deposit)?;
+			AppKeyDeposits::<T>::insert(id, (owner.clone(), deposit));
 
 			Self::deposit_event(Event::ApplicationKeyCreated { key, owner, id });
 			Ok(().into())
@@ -185,19 +340,14 @@ pub mod pallet {
 			data: AppDataFor<T>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			ensure!(!data.is_empty(), Error::<T>::DataCannotBeEmpty);
-
-			let data_hash = blake2_256(&data);
-			Self::deposit_event(Event::DataSubmitted {
-				who,
-				data_hash: H256(data_hash),
-			});
-
-			Ok(().into())
+			Self::do_submit_data(who, &data, CompressionCodec::None, None)
 		}
 
+		/// Proposes new block dimensions, which are scheduled `BlockLengthEnactmentDelay` blocks
+		/// ahead, or applied immediately if that delay is zero.
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::submit_block_length_proposal())]
+		#[pallet::weight(T::WeightInfo::submit_block_length_proposal()
+			.max(T::WeightInfo::schedule_block_length_proposal()))]
 		pub fn submit_block_length_proposal(
 			origin: OriginFor<T>,
 			rows: u32,
@@ -206,26 +356,15 @@ pub mod pallet {
 			ensure_root(origin)?;
 			let rows = BlockLengthRows(rows);
 			let cols = BlockLengthColumns(cols);
-
-			ensure!(
-				rows <= T::MaxBlockRows::get() && cols <= T::MaxBlockCols::get(),
-				Error::<T>::BlockDimensionsOutOfBounds
-			);
-			ensure!(
-				rows >= T::MinBlockRows::get() && cols >= T::MinBlockCols::get(),
-				Error::<T>::BlockDimensionsTooSmall
-			);
-
-			// Check if rows and cols are powers of 2
-			// Check if `rows` or `cols` are a power of 2: they must be nonzero and have no bits in common with `(rows or cols) - 1`.
-			ensure!(
-				rows.0 != 0 && (rows.0 & (rows.0 - 1)) == 0,
-				Error::<T>::NotPowerOfTwo
-			);
-			ensure!(
-				cols.0 != 0 && (cols.0 & (cols.0 - 1)) == 0,
-				Error::<T>::NotPowerOfTwo
-			);
+			let block_length = Self::proposed_block_length(rows, cols)?;
+
+			let delay = T::BlockLengthEnactmentDelay::get();
+			if delay != 0 {
+				let enact_at =
+					frame_system::Pallet::<T>::block_number().saturating_add(delay.into());
+				Self::schedule_block_length(rows, cols, enact_at);
+				return Ok(().into());
+			}
 
 			let current_block_dimension = DynamicBlockLength::<T>::get();
 			let is_increase =
@@ -235,11 +374,7 @@ pub mod pallet {
 				Error::<T>::InvalidBlockWeightReduction
 			);
 
-			let block_length =
-				BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO)
-					.map_err(|_| Error::<T>::BlockDimensionsOutOfBounds)?;
-
-			DynamicBlockLength::<T>::put(block_length);
+			Self::set_block_length(block_length);
 
 			Self::deposit_event(Event::BlockLengthProposalSubmitted { rows, cols });
 
@@ -255,24 +390,8 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
 
-			ensure!(!old_key.is_empty(), Error::<T>::AppKeyCannotBeEmpty);
-			ensure!(!new_key.is_empty(), Error::<T>::AppKeyCannotBeEmpty);
-
-			// Check for uniqueness
-			let maybe_existing_key = AppKeys::<T>::get(&new_key);
-			ensure!(
-				maybe_existing_key.is_none(),
-				Error::<T>::AppKeyAlreadyExists
-			);
-
-			// Get app info for the given key
-			let app_key_info = AppKeys::<T>::get(&old_key).ok_or(Error::<T>::UnknownAppKey)?;
-
-			// Remove the old key
-			AppKeys::<T>::remove(&old_key);
-
-			// Insert the app info under the new key
-			AppKeys::<T>::insert(&new_key, app_key_info);
+			let id = Self::do_rename_application_key(&old_key, &new_key)?;
+			Self::update_app_key_deposit(id, new_key.len(), None)?;
 
 			Self::deposit_event(Event::ApplicationKeySet { old_key, new_key });
 
@@ -293,6 +412,302 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Makes the application of `key` permissioned, so only the accounts in its allow-list
+		/// can submit data to it, or open again.
+		///
+		/// Only the owner of `key` can call it. The allow-list is kept when the app is opened.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_app_permissioned())]
+		pub fn set_app_permissioned(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+			permissioned: bool,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let id = Self::owned_app_key_info(&key, &who)?.id;
+
+			if permissioned {
+				PermissionedApps::<T>::insert(id, ());
+			} else {
+				PermissionedApps::<T>::remove(id);
+			}
+
+			Self::deposit_event(Event::AppPermissionedSet { id, permissioned });
+
+			Ok(().into())
+		}
+
+		/// Adds `submitter` to the allow-list of the application of `key`.
+		///
+		/// Only the owner of `key` can call it.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::add_app_submitter())]
+		pub fn add_app_submitter(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+			submitter: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let id = Self::owned_app_key_info(&key, &who)?.id;
+			ensure!(
+				!AppSubmitters::<T>::contains_key(id, &submitter),
+				Error::<T>::AppSubmitterAlreadyExists
+			);
+
+			AppSubmitters::<T>::insert(id, &submitter, ());
+
+			Self::deposit_event(Event::AppSubmitterAdded { id, submitter });
+
+			Ok(().into())
+		}
+
+		/// Removes `submitter` from the allow-list of the application of `key`.
+		///
+		/// Only the owner of `key` can call it.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::remove_app_submitter())]
+		pub fn remove_app_submitter(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+			submitter: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let id = Self::owned_app_key_info(&key, &who)?.id;
+			ensure!(
+				AppSubmitters::<T>::contains_key(id, &submitter),
+				Error::<T>::UnknownAppSubmitter
+			);
+
+			AppSubmitters::<T>::remove(id, &submitter);
+
+			Self::deposit_event(Event::AppSubmitterRemoved { id, submitter });
+
+			Ok(().into())
+		}
+
+		/// Transfers the ownership of the application of `key` to `new_owner`.
+		///
+		/// Only the owner of `key` can call it, and the application must not be retired.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::transfer_application_key_ownership())]
+		pub fn transfer_application_key_ownership(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+			new_owner: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let info = Self::owned_app_key_info(&key, &who)?;
+			ensure!(
+				!RetiredApps::<T>::contains_key(info.id),
+				Error::<T>::AppKeyRetired
+			);
+
+			AppKeys::<T>::insert(
+				&key,
+				AppKeyInfo {
+					owner: new_owner.clone(),
+					id: info.id,
+				},
+			);
+
+			Self::deposit_event(Event::ApplicationKeyOwnershipTransferred {
+				key,
+				old_owner: who,
+				new_owner,
+			});
+
+			Ok(().into())
+		}
+
+		/// Renames the application key `old_key` to `new_key`, keeping its ID and owner.
+		///
+		/// Only the owner of `old_key` can call it, and the application must not be retired.
+		/// The deposit is re-computed for `new_key` and held by the caller from then on.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::rename_application_key())]
+		pub fn rename_application_key(
+			origin: OriginFor<T>,
+			old_key: AppKeyFor<T>,
+			new_key: AppKeyFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let info = Self::owned_app_key_info(&old_key, &who)?;
+			ensure!(
+				!RetiredApps::<T>::contains_key(info.id),
+				Error::<T>::AppKeyRetired
+			);
+
+			Self::do_rename_application_key(&old_key, &new_key)?;
+			Self::update_app_key_deposit(info.id, new_key.len(), Some(&who))?;
+
+			Self::deposit_event(Event::ApplicationKeyRenamed {
+				old_key,
+				new_key,
+				owner: who,
+			});
+
+			Ok(().into())
+		}
+
+		/// Retires the application of `key`, so no more data can be submitted to it.
+		///
+		/// Only the owner of `key` can call it. The key and its ID stay registered, so they
+		/// cannot be reused, and retirement cannot be undone. The deposit of the key is returned
+		/// to the account which paid it.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::retire_application_key())]
+		pub fn retire_application_key(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let id = Self::owned_app_key_info(&key, &who)?.id;
+			ensure!(
+				!RetiredApps::<T>::contains_key(id),
+				Error::<T>::AppKeyRetired
+			);
+
+			RetiredApps::<T>::insert(id, ());
+			if let Some((depositor, deposit)) = AppKeyDeposits::<T>::take(id) {
+				let _ = T::Currency::unreserve(&depositor, deposit);
+			}
+
+			Self::deposit_event(Event::ApplicationKeyRetired { key, id });
+
+			Ok(().into())
+		}
+
+		/// Sets the fee modifier of `submit_data` for the application `app_id`, or removes it if
+		/// `modifier` is `None`, so the global `SubmitDataFeeModifier` applies again.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_app_fee_modifier())]
+		pub fn set_app_fee_modifier(
+			origin: OriginFor<T>,
+			app_id: AppId,
+			modifier: Option<DispatchFeeModifier>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			AppFeeModifiers::<T>::set(app_id, modifier);
+
+			Self::deposit_event(Event::AppFeeModifierSet {
+				id: app_id,
+				value: modifier,
+			});
+
+			Ok(().into())
+		}
+
+		/// Sets the block space quota of the application `app_id`, or removes it if `quota` is
+		/// `None`.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::set_app_block_quota())]
+		pub fn set_app_block_quota(
+			origin: OriginFor<T>,
+			app_id: AppId,
+			quota: Option<AppBlockQuota>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			AppBlockQuotas::<T>::set(app_id, quota);
+
+			Self::deposit_event(Event::AppBlockQuotaSet { id: app_id, quota });
+
+			Ok(().into())
+		}
+
+		/// Submits `data` which was compressed off-chain using `codec`, tagged with `namespace`
+		/// if it is set, so it can be told apart from other data of the same application.
+		///
+		/// Weight and fees are charged on the compressed length.
+		#[pallet::call_index(13)]
+		#[pallet::weight((
+			weight_helper::submit_data::<T>(data.len()),
+			DispatchClass::Normal,
+			SubmitDataFeeModifier::<T>::get()
+		))]
+		pub fn submit_data_with_options(
+			origin: OriginFor<T>,
+			data: AppDataFor<T>,
+			codec: CompressionCodec,
+			namespace: Option<Namespace>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_submit_data(who, &data, codec, namespace)
+		}
+
+		/// Schedules new block dimensions which will be applied at the beginning of block
+		/// `enact_at`, replacing any pending proposal.
+		///
+		/// `enact_at` must be at least `BlockLengthEnactmentDelay` blocks ahead.
+		///
+		/// Reductions are allowed too, because they are applied before any extrinsic.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::schedule_block_length_proposal())]
+		pub fn schedule_block_length_proposal(
+			origin: OriginFor<T>,
+			rows: u32,
+			cols: u32,
+			enact_at: BlockNumberFor<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let rows = BlockLengthRows(rows);
+			let cols = BlockLengthColumns(cols);
+			Self::proposed_block_length(rows, cols)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(enact_at > now, Error::<T>::EnactmentBlockInPast);
+			ensure!(
+				enact_at >= now.saturating_add(T::BlockLengthEnactmentDelay::get().into()),
+				Error::<T>::EnactmentTooSoon
+			);
+
+			Self::schedule_block_length(rows, cols, enact_at);
+
+			Ok(().into())
+		}
+
+		/// Enables the automatic scaling of block rows based on the DA demand, or disables it if
+		/// `params` is `None`.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::set_block_length_auto_scaling())]
+		pub fn set_block_length_auto_scaling(
+			origin: OriginFor<T>,
+			params: Option<BlockLengthAutoScaling>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			if let Some(params) = params {
+				ensure!(
+					params.window > 0 && params.window <= T::MaxUtilisationWindow::get(),
+					Error::<T>::InvalidAutoScalingParams
+				);
+				ensure!(
+					params.scale_down_threshold < params.scale_up_threshold,
+					Error::<T>::InvalidAutoScalingParams
+				);
+			}
+
+			BlockLengthAutoScalingParams::<T>::set(params);
+			BlockUtilisation::<T>::kill();
+
+			Self::deposit_event(Event::BlockLengthAutoScalingSet { params });
+
+			Ok(().into())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			// Automatic changes go through the pending proposal, so they are enacted right away
+			// when there is no enactment delay.
+			Self::auto_adjust_block_length(now)
+				.saturating_add(Self::enact_pending_block_length(now))
+		}
+
+		fn on_finalize(_now: BlockNumberFor<T>) {
+			Self::record_block_utilisation();
+		}
 	}
 
 	/// Event for the pallet.
@@ -308,6 +723,8 @@ pub mod pallet {
 		DataSubmitted {
 			who: T::AccountId,
 			data_hash: H256,
+			codec: CompressionCodec,
+			namespace: Option<Namespace>,
 		},
 		BlockLengthProposalSubmitted {
 			rows: BlockLengthRows,
@@ -320,6 +737,68 @@ pub mod pallet {
 		SubmitDataFeeModifierSet {
 			value: DispatchFeeModifier,
 		},
+		/// An application became permissioned or open.
+		AppPermissionedSet {
+			id: AppId,
+			permissioned: bool,
+		},
+		/// An account was added to the allow-list of an application.
+		AppSubmitterAdded {
+			id: AppId,
+			submitter: T::AccountId,
+		},
+		/// An account was removed from the allow-list of an application.
+		AppSubmitterRemoved {
+			id: AppId,
+			submitter: T::AccountId,
+		},
+		/// The ownership of an application key was transferred by its owner.
+		ApplicationKeyOwnershipTransferred {
+			key: AppKeyFor<T>,
+			old_owner: T::AccountId,
+			new_owner: T::AccountId,
+		},
+		/// An application key was renamed by its owner.
+		ApplicationKeyRenamed {
+			old_key: AppKeyFor<T>,
+			new_key: AppKeyFor<T>,
+			owner: T::AccountId,
+		},
+		/// An application key was retired by its owner.
+		ApplicationKeyRetired {
+			key: AppKeyFor<T>,
+			id: AppId,
+		},
+		/// The fee modifier of `submit_data` for an application was set or removed.
+		AppFeeModifierSet {
+			id: AppId,
+			value: Option<DispatchFeeModifier>,
+		},
+		/// The block space quota of an application was set or removed.
+		AppBlockQuotaSet {
+			id: AppId,
+			quota: Option<AppBlockQuota>,
+		},
+		/// New block dimensions were scheduled for block `enact_at`.
+		BlockLengthChangeScheduled {
+			rows: BlockLengthRows,
+			cols: BlockLengthColumns,
+			enact_at: BlockNumberFor<T>,
+		},
+		/// Scheduled block dimensions were applied.
+		BlockLengthChangeEnacted {
+			rows: BlockLengthRows,
+			cols: BlockLengthColumns,
+		},
+		/// The automatic block length scaling was enabled, updated or disabled.
+		BlockLengthAutoScalingSet {
+			params: Option<BlockLengthAutoScaling>,
+		},
+		/// Block rows were scheduled to be adjusted to the DA demand.
+		BlockLengthAutoAdjusted {
+			rows: BlockLengthRows,
+			cols: BlockLengthColumns,
+		},
 	}
 
 	/// Error for the System pallet
@@ -347,6 +826,22 @@ pub mod pallet {
 		UnknownAppKey,
 		/// Submit block length proposal was made with values not power of 2
 		NotPowerOfTwo,
+		/// The caller is not the owner of the application key
+		NotAppOwner,
+		/// The account is already in the allow-list of the application
+		AppSubmitterAlreadyExists,
+		/// The account is not in the allow-list of the application
+		UnknownAppSubmitter,
+		/// The application key was retired
+		AppKeyRetired,
+		/// The enactment block of a block length proposal is not in the future
+		EnactmentBlockInPast,
+		/// The window of the automatic block length scaling is empty or too long, or its
+		/// thresholds overlap
+		InvalidAutoScalingParams,
+		/// The enactment block of a block length proposal is less than
+		/// `BlockLengthEnactmentDelay` blocks ahead
+		EnactmentTooSoon,
 	}
 
 	#[pallet::genesis_config]
@@ -377,6 +872,7 @@ pub mod pallet {
 					id: AppId(id),
 					owner,
 				};
+				AppIdToKey::<T>::insert(value.id, &key);
 				AppKeys::<T>::insert(key, value);
 			}
 		}
@@ -392,6 +888,292 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Returns the block length of the proposed dimensions, if they are within bounds and powers
+	/// of 2.
+	fn proposed_block_length(
+		rows: BlockLengthRows,
+		cols: BlockLengthColumns,
+	) -> Result<BlockLength, Error<T>> {
+		ensure!(
+			rows <= T::MaxBlockRows::get() && cols <= T::MaxBlockCols::get(),
+			Error::<T>::BlockDimensionsOutOfBounds
+		);
+		ensure!(
+			rows >= T::MinBlockRows::get() && cols >= T::MinBlockCols::get(),
+			Error::<T>::BlockDimensionsTooSmall
+		);
+
+		// Check if rows and cols are powers of 2
+		// Check if `rows` or `cols` are a power of 2: they must be nonzero and have no bits in common with `(rows or cols) - 1`.
+		ensure!(
+			rows.0 != 0 && (rows.0 & (rows.0 - 1)) == 0,
+			Error::<T>::NotPowerOfTwo
+		);
+		ensure!(
+			cols.0 != 0 && (cols.0 & (cols.0 - 1)) == 0,
+			Error::<T>::NotPowerOfTwo
+		);
+
+		BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO)
+			.map_err(|_| Error::<T>::BlockDimensionsOutOfBounds)
+	}
+
+	/// Sets the block length, discarding the utilisation samples recorded with the previous one.
+	fn set_block_length(block_length: BlockLength) {
+		DynamicBlockLength::<T>::put(block_length);
+		BlockUtilisation::<T>::kill();
+	}
+
+	/// Schedules new block dimensions at `enact_at`, replacing any pending proposal.
+	fn schedule_block_length(
+		rows: BlockLengthRows,
+		cols: BlockLengthColumns,
+		enact_at: BlockNumberFor<T>,
+	) {
+		PendingBlockLengthProposal::<T>::put(PendingBlockLength {
+			enact_at,
+			rows,
+			cols,
+		});
+
+		Self::deposit_event(Event::BlockLengthChangeScheduled {
+			rows,
+			cols,
+			enact_at,
+		});
+	}
+
+	/// Applies the pending block length proposal if its enactment block is `now`, or it was
+	/// missed.
+	fn enact_pending_block_length(now: BlockNumberFor<T>) -> Weight {
+		let pending = match PendingBlockLengthProposal::<T>::get() {
+			Some(pending) if pending.enact_at <= now => pending,
+			_ => return T::DbWeight::get().reads(1),
+		};
+		PendingBlockLengthProposal::<T>::kill();
+
+		let PendingBlockLength { rows, cols, .. } = pending;
+		match BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO) {
+			Ok(block_length) => {
+				Self::set_block_length(block_length);
+				Self::deposit_event(Event::BlockLengthChangeEnacted { rows, cols });
+			},
+			Err(e) => log::error!(
+				target: LOG_TARGET,
+				"Pending block length {rows:?}x{cols:?} cannot be enacted: {e:?}"
+			),
+		}
+
+		T::WeightInfo::enact_block_length_proposal()
+	}
+
+	/// Records the utilisation of the block space by the current block, if the automatic block
+	/// length scaling is enabled.
+	///
+	/// It is done on finalization because `AllExtrinsicsLen` is cleared when the next block is
+	/// initialized.
+	fn record_block_utilisation() {
+		let Some(params) = BlockLengthAutoScalingParams::<T>::get() else {
+			return;
+		};
+
+		let block_length = DynamicBlockLength::<T>::get();
+		let max_scalars = block_length.rows.0.saturating_mul(block_length.cols.0);
+		let used_scalars = AllExtrinsicsLen::<T>::get()
+			.and_then(|len| len.total_num_scalars())
+			.unwrap_or_default();
+		let utilisation = Perbill::from_rational(used_scalars, max_scalars);
+
+		BlockUtilisation::<T>::mutate(|samples| {
+			let window = params.window as usize;
+			while !samples.is_empty() && samples.len() >= window {
+				samples.remove(0);
+			}
+			if samples.try_push(utilisation).is_err() {
+				log::error!(target: LOG_TARGET, "Block utilisation window is full");
+			}
+		});
+	}
+
+	/// Schedules the block rows to be doubled or halved, within `MinBlockRows..MaxBlockRows`,
+	/// `BlockLengthEnactmentDelay` blocks ahead when the average utilisation over a whole window
+	/// crosses the thresholds of the automatic scaling.
+	///
+	/// Nothing is scheduled while another block length proposal is pending.
+	/// The returned weight also covers `record_block_utilisation` on finalization.
+	fn auto_adjust_block_length(now: BlockNumberFor<T>) -> Weight {
+		let Some(params) = BlockLengthAutoScalingParams::<T>::get() else {
+			return T::DbWeight::get().reads(2);
+		};
+		let weight = T::WeightInfo::auto_adjust_block_length();
+		if PendingBlockLengthProposal::<T>::exists() {
+			return weight;
+		}
+
+		let samples = BlockUtilisation::<T>::get();
+		if samples.len() < params.window as usize {
+			return weight;
+		}
+
+		let total = samples
+			.iter()
+			.map(|sample| u64::from(sample.deconstruct()))
+			.sum::<u64>();
+		let max_total = u64::from(Perbill::ACCURACY).saturating_mul(samples.len() as u64);
+		let average = Perbill::from_rational(total, max_total);
+
+		let current = DynamicBlockLength::<T>::get();
+		let rows = if average > params.scale_up_threshold {
+			current.rows.0.saturating_mul(2)
+		} else if average < params.scale_down_threshold {
+			current.rows.0 / 2
+		} else {
+			return weight;
+		};
+
+		// Rows stay as they are at the bounds.
+		let (rows, cols) = (BlockLengthRows(rows), current.cols);
+		if Self::proposed_block_length(rows, cols).is_err() {
+			return weight;
+		}
+		let enact_at = now.saturating_add(T::BlockLengthEnactmentDelay::get().into());
+		Self::schedule_block_length(rows, cols, enact_at);
+		Self::deposit_event(Event::BlockLengthAutoAdjusted { rows, cols });
+
+		weight
+	}
+
+	/// Returns the pending block length proposal, if any.
+	pub fn pending_block_length() -> Option<PendingBlockLengthFor<T>> {
+		PendingBlockLengthProposal::<T>::get()
+	}
+
+	/// Emits `DataSubmitted` for non-empty `data` submitted by `who`.
+	fn do_submit_data(
+		who: T::AccountId,
+		data: &AppDataFor<T>,
+		codec: CompressionCodec,
+		namespace: Option<Namespace>,
+	) -> DispatchResultWithPostInfo {
+		ensure!(!data.is_empty(), Error::<T>::DataCannotBeEmpty);
+
+		let data_hash = blake2_256(data);
+		Self::deposit_event(Event::DataSubmitted {
+			who,
+			data_hash: H256(data_hash),
+			codec,
+			namespace,
+		});
+
+		Ok(().into())
+	}
+
+	/// Returns true if `who` can submit data to `app_id`, which happens if the application is
+	/// open or `who` is in its allow-list. `AppId(0)` is always open.
+	pub fn is_allowed_submitter(app_id: AppId, who: &T::AccountId) -> bool {
+		app_id == AppId(0)
+			|| !PermissionedApps::<T>::contains_key(app_id)
+			|| AppSubmitters::<T>::contains_key(app_id, who)
+	}
+
+	/// Returns the maximum number of padded scalars which `app_id` can use in a block of
+	/// `block_scalars`, if it has a quota.
+	pub fn app_quota_scalars(app_id: AppId, block_scalars: u32) -> Option<u32> {
+		AppBlockQuotas::<T>::get(app_id).map(|quota| quota.max_scalars(block_scalars))
+	}
+
+	/// Returns the deposit of an application key of `key_len` bytes.
+	pub fn app_key_deposit(key_len: usize) -> BalanceOf<T> {
+		let key_len: u32 = key_len.saturated_into();
+		T::AppKeyByteDeposit::get()
+			.saturating_mul(key_len.into())
+			.saturating_add(T::AppKeyDeposit::get())
+	}
+
+	/// Returns the key and information of the application `app_id`.
+	pub fn app_info(app_id: AppId) -> Option<(AppKeyFor<T>, AppKeyInfoFor<T>)> {
+		let key = AppIdToKey::<T>::get(app_id)?;
+		let info = AppKeys::<T>::get(&key)?;
+		Some((key, info))
+	}
+
+	/// Returns true if the application `app_id` was retired by its owner.
+	pub fn is_retired(app_id: AppId) -> bool {
+		RetiredApps::<T>::contains_key(app_id)
+	}
+
+	/// Returns the information of the application `key`, if it is owned by `who`.
+	fn owned_app_key_info(
+		key: &AppKeyFor<T>,
+		who: &T::AccountId,
+	) -> Result<AppKeyInfoFor<T>, Error<T>> {
+		let info = AppKeys::<T>::get(key).ok_or(Error::<T>::UnknownAppKey)?;
+		ensure!(info.owner == *who, Error::<T>::NotAppOwner);
+		Ok(info)
+	}
+
+	/// Moves the application information of `old_key` under `new_key`.
+	fn do_rename_application_key(
+		old_key: &AppKeyFor<T>,
+		new_key: &AppKeyFor<T>,
+	) -> Result<AppId, Error<T>> {
+		ensure!(!old_key.is_empty(), Error::<T>::AppKeyCannotBeEmpty);
+		ensure!(!new_key.is_empty(), Error::<T>::AppKeyCannotBeEmpty);
+
+		// Check for uniqueness
+		ensure!(
+			!AppKeys::<T>::contains_key(new_key),
+			Error::<T>::AppKeyAlreadyExists
+		);
+
+		// Get app info for the given key
+		let app_key_info = AppKeys::<T>::take(old_key).ok_or(Error::<T>::UnknownAppKey)?;
+
+		// Insert the app info under the new key
+		let id = app_key_info.id;
+		AppIdToKey::<T>::insert(id, new_key);
+		AppKeys::<T>::insert(new_key, app_key_info);
+
+		Ok(id)
+	}
+
+	/// Re-computes the deposit of the application `id` for a key of `key_len` bytes.
+	///
+	/// The deposit is moved to `payer` if given, and otherwise stays with the account which paid
+	/// it, only the difference being reserved or unreserved. Applications without a deposit are
+	/// left as they are.
+	///
+	/// Without `payer` the top-up is best-effort: the depositor keeps the old deposit if it
+	/// cannot afford the new one, so renames by root never fail because of it.
+	fn update_app_key_deposit(
+		id: AppId,
+		key_len: usize,
+		payer: Option<&T::AccountId>,
+	) -> DispatchResult {
+		let Some((depositor, old_deposit)) = AppKeyDeposits::<T>::get(id) else {
+			return Ok(());
+		};
+		let forced = payer.is_none();
+		let payer = payer.unwrap_or(&depositor).clone();
+		let mut new_deposit = Self::app_key_deposit(key_len);
+
+		if payer != depositor {
+			T::Currency::reserve(&payer, new_deposit)?;
+			let _ = T::Currency::unreserve(&depositor, old_deposit);
+		} else if new_deposit > old_deposit {
+			let top_up = new_deposit.saturating_sub(old_deposit);
+			if let Err(e) = T::Currency::reserve(&payer, top_up) {
+				ensure!(forced, e);
+				new_deposit = old_deposit;
+			}
+		} else {
+			let _ = T::Currency::unreserve(&payer, old_deposit.saturating_sub(new_deposit));
+		}
+
+		AppKeyDeposits::<T>::insert(id, (payer, new_deposit));
+		Ok(())
+	}
+
 	/// Check if the block weight is acceptable to execute the extrinsic
 	/// We check the current normal ratio weight, if it's too high, it means we won't reduce the block size
 	pub fn is_block_weight_acceptable() -> bool {
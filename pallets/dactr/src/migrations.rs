@@ -0,0 +1,67 @@
+//! Storage migrations of `da_control`.
+
+pub mod v1 {
+	use crate::{AppIdToKey, AppKeys, Config, Pallet, LOG_TARGET};
+	use frame_support::{
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+		weights::Weight,
+	};
+	use sp_std::marker::PhantomData;
+	#[cfg(feature = "try-runtime")]
+	use {
+		codec::{Decode, Encode},
+		sp_runtime::TryRuntimeError,
+		sp_std::vec::Vec,
+	};
+
+	/// Backfills the `AppIdToKey` index from the existing `AppKeys`.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 0 {
+				log::info!(
+					target: LOG_TARGET,
+					"Skipping `AppIdToKey` migration, on-chain storage version is {on_chain:?}"
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut count = 0u64;
+			for (key, info) in AppKeys::<T>::iter() {
+				AppIdToKey::<T>::insert(info.id, key);
+				count = count.saturating_add(1);
+			}
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: LOG_TARGET, "Migrated {count} application keys into `AppIdToKey`");
+			T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let count = AppKeys::<T>::iter().count() as u32;
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let count = u32::decode(&mut state.as_slice())
+				.map_err(|_| TryRuntimeError::Other("Invalid pre-upgrade state"))?;
+			frame_support::ensure!(
+				AppIdToKey::<T>::iter().count() as u32 == count,
+				"`AppIdToKey` does not index every application key"
+			);
+			frame_support::ensure!(
+				AppKeys::<T>::iter().all(|(key, info)| AppIdToKey::<T>::get(info.id) == Some(key)),
+				"`AppIdToKey` is not consistent with `AppKeys`"
+			);
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"Storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
@@ -77,8 +77,19 @@ impl pallet_utility::Config for Test {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const AppKeyDeposit: Balance = 10 * AVAIL;
+	pub const AppKeyByteDeposit: Balance = AVAIL;
+	pub static BlockLengthEnactmentDelay: u32 = 0;
+}
+
 #[derive_impl(da_control::config_preludes::TestDefaultConfig)]
-impl da_control::Config for Test {}
+impl da_control::Config for Test {
+	type AppKeyByteDeposit = AppKeyByteDeposit;
+	type AppKeyDeposit = AppKeyDeposit;
+	type BlockLengthEnactmentDelay = BlockLengthEnactmentDelay;
+	type Currency = Balances;
+}
 
 /// Create new externalities for `System` module tests.
 pub fn new_test_ext() -> sp_io::TestExternalities {
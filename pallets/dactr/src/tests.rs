@@ -1,14 +1,19 @@
 use avail_core::{BlockLengthColumns, BlockLengthRows, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO};
-use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use frame_support::{assert_noop, assert_ok, error::BadOrigin, traits::Hooks};
 use frame_system::{limits::BlockLength, RawOrigin};
 use sp_core::H256;
+use sp_runtime::Perbill;
 
 use crate::config_preludes::{
-	MaxAppDataLength, MaxBlockCols, MaxBlockRows, MinBlockCols, MinBlockRows,
+	MaxAppDataLength, MaxBlockCols, MaxBlockRows, MaxUtilisationWindow, MinBlockCols, MinBlockRows,
 };
 use crate::{
-	mock::{new_test_ext, DataAvailability, RuntimeEvent, RuntimeOrigin, System, Test},
-	AppDataFor, AppKeyFor, AppKeyInfoFor, Event,
+	mock::{
+		new_test_ext, BlockLengthEnactmentDelay, DataAvailability, RuntimeEvent, RuntimeOrigin,
+		System, Test,
+	},
+	AppDataFor, AppKeyFor, AppKeyInfoFor, BlockLengthAutoScaling, BlockLengthAutoScalingParams,
+	BlockUtilisation, CompressionCodec, Event, PendingBlockLength,
 };
 
 type Error = crate::Error<Test>;
@@ -91,6 +96,8 @@ mod submit_data {
 			let event = RuntimeEvent::DataAvailability(Event::DataSubmitted {
 				who: ALICE,
 				data_hash,
+				codec: CompressionCodec::None,
+				namespace: None,
 			});
 			System::assert_last_event(event);
 		})
@@ -116,6 +123,57 @@ mod submit_data {
 			assert!(err.is_err());
 		})
 	}
+
+	#[test]
+	fn submit_data_with_options() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let data = AppDataFor::<Test>::try_from(vec![b'X'; 32]).unwrap();
+			let data_hash = H256(sp_io::hashing::blake2_256(&data));
+
+			assert_ok!(DataAvailability::submit_data_with_options(
+				alice.clone(),
+				data.clone(),
+				CompressionCodec::Zstd,
+				None
+			));
+			System::assert_last_event(RuntimeEvent::DataAvailability(Event::DataSubmitted {
+				who: ALICE,
+				data_hash,
+				codec: CompressionCodec::Zstd,
+				namespace: None,
+			}));
+
+			assert_ok!(DataAvailability::submit_data_with_options(
+				alice,
+				data,
+				CompressionCodec::None,
+				Some(7)
+			));
+			System::assert_last_event(RuntimeEvent::DataAvailability(Event::DataSubmitted {
+				who: ALICE,
+				data_hash,
+				codec: CompressionCodec::None,
+				namespace: Some(7),
+			}));
+		})
+	}
+
+	#[test]
+	fn data_with_options_cannot_be_empty() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let data = AppDataFor::<Test>::try_from(vec![]).unwrap();
+
+			let err = DataAvailability::submit_data_with_options(
+				alice,
+				data,
+				CompressionCodec::Brotli,
+				Some(7),
+			);
+			assert_noop!(err, Error::DataCannotBeEmpty);
+		})
+	}
 }
 
 mod submit_block_length_proposal {
@@ -190,6 +248,37 @@ mod submit_block_length_proposal {
 		})
 	}
 
+	#[test]
+	fn submit_block_length_proposal_with_enactment_delay() {
+		new_test_ext().execute_with(|| {
+			BlockLengthEnactmentDelay::set(10);
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let rows = BlockLengthRows(128);
+			let cols = BlockLengthColumns(128);
+			let enact_at = System::block_number() + 10;
+			let old_block_length = System::block_length();
+
+			assert_ok!(DataAvailability::submit_block_length_proposal(
+				root, rows.0, cols.0
+			));
+
+			let pending = PendingBlockLength {
+				enact_at,
+				rows,
+				cols,
+			};
+			assert_eq!(DataAvailability::pending_block_length(), Some(pending));
+			assert_eq!(System::block_length(), old_block_length);
+
+			let event = RuntimeEvent::DataAvailability(Event::BlockLengthChangeScheduled {
+				rows,
+				cols,
+				enact_at,
+			});
+			System::assert_last_event(event);
+		})
+	}
+
 	#[test]
 	fn bad_origin() {
 		new_test_ext().execute_with(|| {
@@ -251,6 +340,426 @@ mod submit_block_length_proposal {
 	}
 }
 
+mod schedule_block_length_proposal {
+	use super::*;
+
+	#[test]
+	fn schedule_block_length_proposal() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let rows = BlockLengthRows(128);
+			let cols = BlockLengthColumns(128);
+			let enact_at = System::block_number() + 2;
+			let old_block_length = System::block_length();
+
+			assert_ok!(DataAvailability::schedule_block_length_proposal(
+				root, rows.0, cols.0, enact_at
+			));
+
+			let pending = PendingBlockLength {
+				enact_at,
+				rows,
+				cols,
+			};
+			assert_eq!(DataAvailability::pending_block_length(), Some(pending));
+			assert_eq!(System::block_length(), old_block_length);
+
+			let event = RuntimeEvent::DataAvailability(Event::BlockLengthChangeScheduled {
+				rows,
+				cols,
+				enact_at,
+			});
+			System::assert_last_event(event);
+
+			DataAvailability::on_initialize(enact_at - 1);
+			assert_eq!(System::block_length(), old_block_length);
+
+			DataAvailability::on_initialize(enact_at);
+			let new_block_length =
+				BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO)
+					.unwrap();
+			assert_eq!(System::block_length(), new_block_length);
+			assert_eq!(DataAvailability::pending_block_length(), None);
+
+			let event =
+				RuntimeEvent::DataAvailability(Event::BlockLengthChangeEnacted { rows, cols });
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn schedule_replaces_pending_proposal() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let enact_at = System::block_number() + 1;
+
+			assert_ok!(DataAvailability::schedule_block_length_proposal(
+				root.clone(),
+				128,
+				128,
+				enact_at
+			));
+			assert_ok!(DataAvailability::schedule_block_length_proposal(
+				root, 64, 64, enact_at
+			));
+
+			let pending = PendingBlockLength {
+				enact_at,
+				rows: BlockLengthRows(64),
+				cols: BlockLengthColumns(64),
+			};
+			assert_eq!(DataAvailability::pending_block_length(), Some(pending));
+		})
+	}
+
+	#[test]
+	fn enactment_block_in_past() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let now = System::block_number();
+
+			let err = DataAvailability::schedule_block_length_proposal(root.clone(), 128, 128, now);
+			assert_noop!(err, Error::EnactmentBlockInPast);
+
+			let err = DataAvailability::schedule_block_length_proposal(root, 128, 128, now - 1);
+			assert_noop!(err, Error::EnactmentBlockInPast);
+		})
+	}
+
+	#[test]
+	fn enactment_too_soon() {
+		new_test_ext().execute_with(|| {
+			BlockLengthEnactmentDelay::set(10);
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let now = System::block_number();
+
+			let err =
+				DataAvailability::schedule_block_length_proposal(root.clone(), 128, 128, now + 9);
+			assert_noop!(err, Error::EnactmentTooSoon);
+
+			assert_ok!(DataAvailability::schedule_block_length_proposal(
+				root,
+				128,
+				128,
+				now + 10
+			));
+		})
+	}
+
+	#[test]
+	fn bad_origin() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let enact_at = System::block_number() + 1;
+
+			let err = DataAvailability::schedule_block_length_proposal(alice, 128, 128, enact_at);
+			assert_noop!(err, BadOrigin);
+		})
+	}
+
+	#[test]
+	fn invalid_block_dimensions() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let enact_at = System::block_number() + 1;
+			let max_rows = MaxBlockRows::get().0;
+			let min_rows = MinBlockRows::get().0;
+
+			let err = DataAvailability::schedule_block_length_proposal(
+				root.clone(),
+				max_rows * 2,
+				128,
+				enact_at,
+			);
+			assert_noop!(err, Error::BlockDimensionsOutOfBounds);
+
+			let err = DataAvailability::schedule_block_length_proposal(
+				root.clone(),
+				min_rows / 2,
+				128,
+				enact_at,
+			);
+			assert_noop!(err, Error::BlockDimensionsTooSmall);
+
+			let err = DataAvailability::schedule_block_length_proposal(root, 118, 128, enact_at);
+			assert_noop!(err, Error::NotPowerOfTwo);
+		})
+	}
+}
+
+mod block_length_auto_scaling {
+	use super::*;
+	use frame_system::{AllExtrinsicsLen, ExtrinsicLenOf};
+
+	fn params(window: u32) -> BlockLengthAutoScaling {
+		BlockLengthAutoScaling {
+			window,
+			scale_up_threshold: Perbill::from_percent(50),
+			scale_down_threshold: Perbill::from_percent(10),
+		}
+	}
+
+	fn set_block_length(rows: u32, cols: u32) {
+		let root: RuntimeOrigin = RawOrigin::Root.into();
+		assert_ok!(DataAvailability::submit_block_length_proposal(
+			root, rows, cols
+		));
+	}
+
+	/// Finalizes the current block, with `len` bytes of submitted data, and initializes the next one.
+	fn next_block(len: u32) {
+		let now = System::block_number();
+		if len > 0 {
+			AllExtrinsicsLen::<Test>::put(ExtrinsicLenOf::<Test>::new(len));
+		}
+		DataAvailability::on_finalize(now);
+		AllExtrinsicsLen::<Test>::kill();
+		System::set_block_number(now + 1);
+		DataAvailability::on_initialize(now + 1);
+	}
+
+	#[test]
+	fn set_block_length_auto_scaling() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let params = params(2);
+
+			assert_ok!(DataAvailability::set_block_length_auto_scaling(
+				root.clone(),
+				Some(params)
+			));
+			assert_eq!(BlockLengthAutoScalingParams::<Test>::get(), Some(params));
+			let event = RuntimeEvent::DataAvailability(Event::BlockLengthAutoScalingSet {
+				params: Some(params),
+			});
+			System::assert_last_event(event);
+
+			assert_ok!(DataAvailability::set_block_length_auto_scaling(root, None));
+			assert_eq!(BlockLengthAutoScalingParams::<Test>::get(), None);
+			let event =
+				RuntimeEvent::DataAvailability(Event::BlockLengthAutoScalingSet { params: None });
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn scale_up_on_high_demand() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			set_block_length(64, 128);
+			assert_ok!(DataAvailability::set_block_length_auto_scaling(
+				root,
+				Some(params(2))
+			));
+
+			// 64x128 scalars are almost full.
+			next_block(250_000);
+			assert_eq!(System::block_length().rows, BlockLengthRows(64));
+			next_block(250_000);
+
+			let rows = BlockLengthRows(128);
+			let cols = BlockLengthColumns(128);
+			let new_block_length =
+				BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO)
+					.unwrap();
+			assert_eq!(System::block_length(), new_block_length);
+			assert!(BlockUtilisation::<Test>::get().is_empty());
+			let event =
+				RuntimeEvent::DataAvailability(Event::BlockLengthAutoAdjusted { rows, cols });
+			System::assert_has_event(event);
+			let event =
+				RuntimeEvent::DataAvailability(Event::BlockLengthChangeEnacted { rows, cols });
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn scale_up_after_enactment_delay() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			set_block_length(64, 128);
+			assert_ok!(DataAvailability::set_block_length_auto_scaling(
+				root,
+				Some(params(2))
+			));
+			BlockLengthEnactmentDelay::set(2);
+
+			next_block(250_000);
+			next_block(250_000);
+
+			let rows = BlockLengthRows(128);
+			let cols = BlockLengthColumns(128);
+			let enact_at = System::block_number() + 2;
+			let pending = PendingBlockLength {
+				enact_at,
+				rows,
+				cols,
+			};
+			assert_eq!(DataAvailability::pending_block_length(), Some(pending));
+			assert_eq!(System::block_length().rows, BlockLengthRows(64));
+			let event = RuntimeEvent::DataAvailability(Event::BlockLengthChangeScheduled {
+				rows,
+				cols,
+				enact_at,
+			});
+			System::assert_has_event(event);
+
+			// The full window does not reschedule the pending change.
+			next_block(250_000);
+			assert_eq!(DataAvailability::pending_block_length(), Some(pending));
+
+			next_block(250_000);
+			assert_eq!(System::block_length().rows, rows);
+			assert_eq!(DataAvailability::pending_block_length(), None);
+			assert!(BlockUtilisation::<Test>::get().is_empty());
+		})
+	}
+
+	#[test]
+	fn scale_down_on_low_demand() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			set_block_length(128, 128);
+			assert_ok!(DataAvailability::set_block_length_auto_scaling(
+				root,
+				Some(params(2))
+			));
+
+			next_block(0);
+			next_block(0);
+			assert_eq!(System::block_length().rows, BlockLengthRows(64));
+
+			// The window starts over after each adjustment.
+			next_block(0);
+			assert_eq!(System::block_length().rows, BlockLengthRows(64));
+			next_block(0);
+			assert_eq!(System::block_length().rows, BlockLengthRows(32));
+
+			// Rows never go below `MinBlockRows`.
+			next_block(0);
+			next_block(0);
+			assert_eq!(System::block_length().rows, MinBlockRows::get());
+		})
+	}
+
+	#[test]
+	fn keep_rows_between_thresholds() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			set_block_length(64, 128);
+			assert_ok!(DataAvailability::set_block_length_auto_scaling(
+				root,
+				Some(params(2))
+			));
+
+			// Around 30% of 64x128 scalars.
+			for _ in 0..4 {
+				next_block(75_000);
+			}
+			assert_eq!(System::block_length().rows, BlockLengthRows(64));
+			assert_eq!(BlockUtilisation::<Test>::get().len(), 2);
+		})
+	}
+
+	#[test]
+	fn submitted_block_length_restarts_window() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			set_block_length(64, 128);
+			assert_ok!(DataAvailability::set_block_length_auto_scaling(
+				root,
+				Some(params(2))
+			));
+
+			next_block(250_000);
+			assert_eq!(BlockUtilisation::<Test>::get().len(), 1);
+
+			set_block_length(64, 256);
+			assert!(BlockUtilisation::<Test>::get().is_empty());
+
+			next_block(250_000);
+			assert_eq!(System::block_length().rows, BlockLengthRows(64));
+			assert_eq!(BlockUtilisation::<Test>::get().len(), 1);
+		})
+	}
+
+	#[test]
+	fn scheduled_block_length_restarts_window() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			set_block_length(64, 128);
+			assert_ok!(DataAvailability::set_block_length_auto_scaling(
+				root.clone(),
+				Some(params(2))
+			));
+			let enact_at = System::block_number() + 2;
+			assert_ok!(DataAvailability::schedule_block_length_proposal(
+				root, 64, 256, enact_at
+			));
+
+			// The window is full when the new block length is enacted.
+			next_block(250_000);
+			next_block(250_000);
+
+			let rows = BlockLengthRows(64);
+			let cols = BlockLengthColumns(256);
+			let new_block_length =
+				BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO)
+					.unwrap();
+			assert_eq!(System::block_length(), new_block_length);
+			assert!(BlockUtilisation::<Test>::get().is_empty());
+			let event =
+				RuntimeEvent::DataAvailability(Event::BlockLengthChangeEnacted { rows, cols });
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn disabled_by_default() {
+		new_test_ext().execute_with(|| {
+			set_block_length(128, 128);
+
+			next_block(0);
+			next_block(0);
+			assert_eq!(System::block_length().rows, BlockLengthRows(128));
+			assert!(BlockUtilisation::<Test>::get().is_empty());
+		})
+	}
+
+	#[test]
+	fn invalid_params() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+
+			let err =
+				DataAvailability::set_block_length_auto_scaling(root.clone(), Some(params(0)));
+			assert_noop!(err, Error::InvalidAutoScalingParams);
+
+			let window = MaxUtilisationWindow::get() + 1;
+			let err =
+				DataAvailability::set_block_length_auto_scaling(root.clone(), Some(params(window)));
+			assert_noop!(err, Error::InvalidAutoScalingParams);
+
+			let overlapping = BlockLengthAutoScaling {
+				scale_down_threshold: Perbill::from_percent(50),
+				..params(2)
+			};
+			let err = DataAvailability::set_block_length_auto_scaling(root, Some(overlapping));
+			assert_noop!(err, Error::InvalidAutoScalingParams);
+		})
+	}
+
+	#[test]
+	fn bad_origin() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+
+			let err = DataAvailability::set_block_length_auto_scaling(alice, Some(params(2)));
+			assert_noop!(err, BadOrigin);
+		})
+	}
+}
+
 mod set_application_key {
 	use super::*;
 
@@ -369,3 +878,602 @@ mod set_submit_data_fee_modifier {
 		})
 	}
 }
+
+mod permissioned_apps {
+	use super::*;
+	use crate::{AppSubmitters, PermissionedApps};
+	use avail_core::AppId;
+
+	const BOB: u64 = 2;
+	const APP_ID: AppId = AppId(1);
+
+	fn bob_key() -> AppKeyFor<Test> {
+		AppKeyFor::<Test>::try_from(b"Reserved-1".to_vec()).unwrap()
+	}
+
+	#[test]
+	fn set_app_permissioned() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+
+			assert!(DataAvailability::is_allowed_submitter(APP_ID, &ALICE));
+			assert_ok!(DataAvailability::set_app_permissioned(
+				bob.clone(),
+				bob_key(),
+				true
+			));
+			assert!(PermissionedApps::<Test>::contains_key(APP_ID));
+			assert!(!DataAvailability::is_allowed_submitter(APP_ID, &ALICE));
+
+			let event = RuntimeEvent::DataAvailability(Event::AppPermissionedSet {
+				id: APP_ID,
+				permissioned: true,
+			});
+			System::assert_last_event(event);
+
+			assert_ok!(DataAvailability::set_app_permissioned(
+				bob,
+				bob_key(),
+				false
+			));
+			assert!(!PermissionedApps::<Test>::contains_key(APP_ID));
+			assert!(DataAvailability::is_allowed_submitter(APP_ID, &ALICE));
+		})
+	}
+
+	#[test]
+	fn add_and_remove_app_submitter() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+
+			assert_ok!(DataAvailability::set_app_permissioned(
+				bob.clone(),
+				bob_key(),
+				true
+			));
+			assert_ok!(DataAvailability::add_app_submitter(
+				bob.clone(),
+				bob_key(),
+				ALICE
+			));
+			assert!(AppSubmitters::<Test>::contains_key(APP_ID, ALICE));
+			assert!(DataAvailability::is_allowed_submitter(APP_ID, &ALICE));
+
+			let event = RuntimeEvent::DataAvailability(Event::AppSubmitterAdded {
+				id: APP_ID,
+				submitter: ALICE,
+			});
+			System::assert_last_event(event);
+
+			let err = DataAvailability::add_app_submitter(bob.clone(), bob_key(), ALICE);
+			assert_noop!(err, Error::AppSubmitterAlreadyExists);
+
+			assert_ok!(DataAvailability::remove_app_submitter(
+				bob.clone(),
+				bob_key(),
+				ALICE
+			));
+			assert!(!DataAvailability::is_allowed_submitter(APP_ID, &ALICE));
+
+			let event = RuntimeEvent::DataAvailability(Event::AppSubmitterRemoved {
+				id: APP_ID,
+				submitter: ALICE,
+			});
+			System::assert_last_event(event);
+
+			let err = DataAvailability::remove_app_submitter(bob, bob_key(), ALICE);
+			assert_noop!(err, Error::UnknownAppSubmitter);
+		})
+	}
+
+	#[test]
+	fn only_owner_can_manage_app() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+
+			let err = DataAvailability::set_app_permissioned(alice.clone(), bob_key(), true);
+			assert_noop!(err, Error::NotAppOwner);
+
+			let err = DataAvailability::add_app_submitter(alice.clone(), bob_key(), ALICE);
+			assert_noop!(err, Error::NotAppOwner);
+
+			let err = DataAvailability::remove_app_submitter(alice, bob_key(), ALICE);
+			assert_noop!(err, Error::NotAppOwner);
+		})
+	}
+
+	#[test]
+	fn unknown_app_key() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+			let key = AppKeyFor::<Test>::try_from(b"NotExisting".to_vec()).unwrap();
+
+			let err = DataAvailability::set_app_permissioned(bob, key, true);
+			assert_noop!(err, Error::UnknownAppKey);
+		})
+	}
+}
+
+mod owner_app_key_management {
+	use super::*;
+	use crate::RetiredApps;
+	use avail_core::AppId;
+
+	const BOB: u64 = 2;
+
+	fn key(raw: &[u8]) -> AppKeyFor<Test> {
+		AppKeyFor::<Test>::try_from(raw.to_vec()).unwrap()
+	}
+
+	#[test]
+	fn transfer_application_key_ownership() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+
+			assert_ok!(DataAvailability::transfer_application_key_ownership(
+				bob,
+				key(b"Reserved-1"),
+				ALICE
+			));
+			assert_eq!(
+				DataAvailability::application_key(key(b"Reserved-1")),
+				Some(AppKeyInfoFor::<Test> {
+					id: AppId(1),
+					owner: ALICE
+				})
+			);
+
+			let event = RuntimeEvent::DataAvailability(Event::ApplicationKeyOwnershipTransferred {
+				key: key(b"Reserved-1"),
+				old_owner: BOB,
+				new_owner: ALICE,
+			});
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn rename_application_key() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+			let old_info = DataAvailability::application_key(key(b"Reserved-1"));
+
+			assert_ok!(DataAvailability::rename_application_key(
+				bob.clone(),
+				key(b"Reserved-1"),
+				key(b"Bob's App")
+			));
+			assert_eq!(DataAvailability::application_key(key(b"Reserved-1")), None);
+			assert_eq!(
+				DataAvailability::application_key(key(b"Bob's App")),
+				old_info
+			);
+
+			let event = RuntimeEvent::DataAvailability(Event::ApplicationKeyRenamed {
+				old_key: key(b"Reserved-1"),
+				new_key: key(b"Bob's App"),
+				owner: BOB,
+			});
+			System::assert_last_event(event);
+
+			let err = DataAvailability::rename_application_key(
+				bob.clone(),
+				key(b"Bob's App"),
+				key(b"Avail"),
+			);
+			assert_noop!(err, Error::AppKeyAlreadyExists);
+
+			let err = DataAvailability::rename_application_key(bob, key(b"Bob's App"), key(b""));
+			assert_noop!(err, Error::AppKeyCannotBeEmpty);
+		})
+	}
+
+	#[test]
+	fn retire_application_key() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+
+			assert!(!DataAvailability::is_retired(AppId(1)));
+			assert_ok!(DataAvailability::retire_application_key(
+				bob.clone(),
+				key(b"Reserved-1")
+			));
+			assert!(RetiredApps::<Test>::contains_key(AppId(1)));
+			assert!(DataAvailability::is_retired(AppId(1)));
+
+			let event = RuntimeEvent::DataAvailability(Event::ApplicationKeyRetired {
+				key: key(b"Reserved-1"),
+				id: AppId(1),
+			});
+			System::assert_last_event(event);
+
+			let err = DataAvailability::retire_application_key(bob.clone(), key(b"Reserved-1"));
+			assert_noop!(err, Error::AppKeyRetired);
+
+			let err = DataAvailability::transfer_application_key_ownership(
+				bob.clone(),
+				key(b"Reserved-1"),
+				ALICE,
+			);
+			assert_noop!(err, Error::AppKeyRetired);
+
+			let err =
+				DataAvailability::rename_application_key(bob, key(b"Reserved-1"), key(b"Other"));
+			assert_noop!(err, Error::AppKeyRetired);
+		})
+	}
+
+	#[test]
+	fn only_owner_can_manage_app_key() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+
+			let err = DataAvailability::transfer_application_key_ownership(
+				alice.clone(),
+				key(b"Reserved-1"),
+				ALICE,
+			);
+			assert_noop!(err, Error::NotAppOwner);
+
+			let err = DataAvailability::rename_application_key(
+				alice.clone(),
+				key(b"Reserved-1"),
+				key(b"Other"),
+			);
+			assert_noop!(err, Error::NotAppOwner);
+
+			let err = DataAvailability::retire_application_key(alice.clone(), key(b"Reserved-1"));
+			assert_noop!(err, Error::NotAppOwner);
+
+			let err = DataAvailability::retire_application_key(alice, key(b"NotExisting"));
+			assert_noop!(err, Error::UnknownAppKey);
+		})
+	}
+}
+
+mod app_id_to_key {
+	use super::*;
+	use crate::{migrations::v1::MigrateToV1, AppIdToKey, Pallet};
+	use avail_core::AppId;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	fn key(raw: &[u8]) -> AppKeyFor<Test> {
+		AppKeyFor::<Test>::try_from(raw.to_vec()).unwrap()
+	}
+
+	#[test]
+	fn genesis_builds_index() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(AppIdToKey::<Test>::get(AppId(0)), Some(key(b"Avail")));
+			assert_eq!(
+				DataAvailability::app_info(AppId(1)),
+				Some((
+					key(b"Reserved-1"),
+					AppKeyInfoFor::<Test> {
+						id: AppId(1),
+						owner: 2
+					}
+				))
+			);
+			assert_eq!(DataAvailability::app_info(AppId(1_000)), None);
+		})
+	}
+
+	#[test]
+	fn index_follows_key_changes() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let new_id = DataAvailability::peek_next_application_id();
+
+			assert_ok!(DataAvailability::create_application_key(
+				alice,
+				key(b"New App")
+			));
+			assert_eq!(AppIdToKey::<Test>::get(new_id), Some(key(b"New App")));
+
+			assert_ok!(DataAvailability::set_application_key(
+				root,
+				key(b"New App"),
+				key(b"Renamed App")
+			));
+			assert_eq!(AppIdToKey::<Test>::get(new_id), Some(key(b"Renamed App")));
+		})
+	}
+
+	#[test]
+	fn migration_backfills_index() {
+		new_test_ext().execute_with(|| {
+			let _ = AppIdToKey::<Test>::clear(u32::MAX, None);
+			StorageVersion::new(0).put::<Pallet<Test>>();
+
+			MigrateToV1::<Test>::on_runtime_upgrade();
+
+			assert_eq!(AppIdToKey::<Test>::get(AppId(0)), Some(key(b"Avail")));
+			assert_eq!(
+				AppIdToKey::<Test>::get(AppId(2)),
+				Some(key(b"A Brave New World"))
+			);
+			assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+		})
+	}
+}
+
+mod app_key_deposit {
+	use super::*;
+	use crate::{mock::Balances, AppKeyDeposits};
+	use avail_core::currency::AVAIL;
+	use frame_support::traits::{Currency, ReservableCurrency};
+
+	const POOR: u64 = 4;
+
+	#[test]
+	fn deposit_is_reserved_and_returned_on_retirement() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = AppKeyFor::<Test>::try_from(b"New App".to_vec()).unwrap();
+			let id = DataAvailability::peek_next_application_id();
+
+			// 10 AVAIL of base deposit plus 1 AVAIL per byte of the key.
+			let deposit = 17 * AVAIL;
+			assert_eq!(DataAvailability::app_key_deposit(key.len()), deposit);
+
+			assert_ok!(DataAvailability::create_application_key(
+				alice.clone(),
+				key.clone()
+			));
+			assert_eq!(Balances::reserved_balance(ALICE), deposit);
+			assert_eq!(AppKeyDeposits::<Test>::get(id), Some((ALICE, deposit)));
+
+			assert_ok!(DataAvailability::retire_application_key(alice, key));
+			assert_eq!(Balances::reserved_balance(ALICE), 0);
+			assert_eq!(AppKeyDeposits::<Test>::get(id), None);
+		})
+	}
+
+	#[test]
+	fn deposit_is_returned_to_depositor_after_transfer() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let bob: RuntimeOrigin = RawOrigin::Signed(2).into();
+			let key = AppKeyFor::<Test>::try_from(b"New App".to_vec()).unwrap();
+
+			assert_ok!(DataAvailability::create_application_key(
+				alice.clone(),
+				key.clone()
+			));
+			assert_ok!(DataAvailability::transfer_application_key_ownership(
+				alice,
+				key.clone(),
+				2
+			));
+			assert_ok!(DataAvailability::retire_application_key(bob, key));
+			assert_eq!(Balances::reserved_balance(ALICE), 0);
+		})
+	}
+
+	#[test]
+	fn rename_to_longer_key_reserves_the_difference() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = AppKeyFor::<Test>::try_from(b"New App".to_vec()).unwrap();
+			let new_key = AppKeyFor::<Test>::try_from(b"Renamed App".to_vec()).unwrap();
+			let id = DataAvailability::peek_next_application_id();
+
+			assert_ok!(DataAvailability::create_application_key(
+				alice.clone(),
+				key.clone()
+			));
+			assert_ok!(DataAvailability::rename_application_key(
+				alice, key, new_key
+			));
+
+			let deposit = 21 * AVAIL;
+			assert_eq!(Balances::reserved_balance(ALICE), deposit);
+			assert_eq!(AppKeyDeposits::<Test>::get(id), Some((ALICE, deposit)));
+		})
+	}
+
+	#[test]
+	fn rename_to_shorter_key_unreserves_the_difference() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = AppKeyFor::<Test>::try_from(b"New App".to_vec()).unwrap();
+			let new_key = AppKeyFor::<Test>::try_from(b"App".to_vec()).unwrap();
+			let id = DataAvailability::peek_next_application_id();
+
+			assert_ok!(DataAvailability::create_application_key(
+				alice.clone(),
+				key.clone()
+			));
+			assert_ok!(DataAvailability::rename_application_key(
+				alice, key, new_key
+			));
+
+			let deposit = 13 * AVAIL;
+			assert_eq!(Balances::reserved_balance(ALICE), deposit);
+			assert_eq!(AppKeyDeposits::<Test>::get(id), Some((ALICE, deposit)));
+		})
+	}
+
+	#[test]
+	fn rename_moves_deposit_to_new_owner() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let bob: RuntimeOrigin = RawOrigin::Signed(2).into();
+			let key = AppKeyFor::<Test>::try_from(b"New App".to_vec()).unwrap();
+			let new_key = AppKeyFor::<Test>::try_from(b"App".to_vec()).unwrap();
+			let id = DataAvailability::peek_next_application_id();
+
+			assert_ok!(DataAvailability::create_application_key(
+				alice.clone(),
+				key.clone()
+			));
+			assert_ok!(DataAvailability::transfer_application_key_ownership(
+				alice,
+				key.clone(),
+				2
+			));
+			assert_ok!(DataAvailability::rename_application_key(bob, key, new_key));
+
+			let deposit = 13 * AVAIL;
+			assert_eq!(Balances::reserved_balance(ALICE), 0);
+			assert_eq!(Balances::reserved_balance(2), deposit);
+			assert_eq!(AppKeyDeposits::<Test>::get(id), Some((2, deposit)));
+		})
+	}
+
+	#[test]
+	fn set_application_key_updates_deposit() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let key = AppKeyFor::<Test>::try_from(b"New App".to_vec()).unwrap();
+			let new_key = AppKeyFor::<Test>::try_from(b"App".to_vec()).unwrap();
+			let id = DataAvailability::peek_next_application_id();
+
+			assert_ok!(DataAvailability::create_application_key(alice, key.clone()));
+			assert_ok!(DataAvailability::set_application_key(root, key, new_key));
+
+			let deposit = 13 * AVAIL;
+			assert_eq!(Balances::reserved_balance(ALICE), deposit);
+			assert_eq!(AppKeyDeposits::<Test>::get(id), Some((ALICE, deposit)));
+		})
+	}
+
+	#[test]
+	fn set_application_key_keeps_deposit_if_top_up_fails() {
+		new_test_ext().execute_with(|| {
+			let charlie: RuntimeOrigin = RawOrigin::Signed(3).into();
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let key = AppKeyFor::<Test>::try_from(b"New App".to_vec()).unwrap();
+			let new_key = AppKeyFor::<Test>::try_from(b"Renamed App".to_vec()).unwrap();
+			let id = DataAvailability::peek_next_application_id();
+
+			assert_ok!(DataAvailability::create_application_key(
+				charlie,
+				key.clone()
+			));
+			Balances::make_free_balance_be(&3, 1);
+			assert_ok!(DataAvailability::set_application_key(
+				root,
+				key,
+				new_key.clone()
+			));
+
+			let deposit = 17 * AVAIL;
+			assert_eq!(Balances::reserved_balance(3), deposit);
+			assert_eq!(AppKeyDeposits::<Test>::get(id), Some((3, deposit)));
+			assert_eq!(DataAvailability::application_key(&new_key).unwrap().id, id);
+		})
+	}
+
+	#[test]
+	fn cannot_create_without_deposit() {
+		new_test_ext().execute_with(|| {
+			let poor: RuntimeOrigin = RawOrigin::Signed(POOR).into();
+			let key = AppKeyFor::<Test>::try_from(b"New App".to_vec()).unwrap();
+
+			let err = DataAvailability::create_application_key(poor, key);
+			assert_noop!(err, pallet_balances::Error::<Test>::InsufficientBalance);
+		})
+	}
+}
+
+mod set_app_fee_modifier {
+	use super::*;
+	use crate::AppFeeModifiers;
+	use avail_core::AppId;
+	use frame_support::dispatch::DispatchFeeModifier;
+
+	const APP_MODIFIER: DispatchFeeModifier = DispatchFeeModifier {
+		weight_maximum_fee: None,
+		weight_fee_divider: Some(2),
+		weight_fee_multiplier: None,
+	};
+
+	#[test]
+	fn only_sudo_can_call_this() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let err = DataAvailability::set_app_fee_modifier(alice, AppId(1), Some(APP_MODIFIER));
+			assert_noop!(err, BadOrigin);
+		})
+	}
+
+	#[test]
+	fn set_app_fee_modifier() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+
+			assert_ok!(DataAvailability::set_app_fee_modifier(
+				root.clone(),
+				AppId(1),
+				Some(APP_MODIFIER)
+			));
+			assert_eq!(AppFeeModifiers::<Test>::get(AppId(1)), Some(APP_MODIFIER));
+
+			let event = RuntimeEvent::DataAvailability(Event::AppFeeModifierSet {
+				id: AppId(1),
+				value: Some(APP_MODIFIER),
+			});
+			System::assert_last_event(event);
+
+			assert_ok!(DataAvailability::set_app_fee_modifier(root, AppId(1), None));
+			assert_eq!(AppFeeModifiers::<Test>::get(AppId(1)), None);
+		})
+	}
+}
+
+mod set_app_block_quota {
+	use super::*;
+	use crate::{AppBlockQuota, AppBlockQuotas};
+	use avail_core::AppId;
+	use sp_runtime::Perbill;
+
+	#[test]
+	fn only_sudo_can_call_this() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let quota = Some(AppBlockQuota::Scalars(128));
+			let err = DataAvailability::set_app_block_quota(alice, AppId(1), quota);
+			assert_noop!(err, BadOrigin);
+		})
+	}
+
+	#[test]
+	fn set_app_block_quota() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let quota = AppBlockQuota::Ratio(Perbill::from_percent(25));
+
+			assert_eq!(DataAvailability::app_quota_scalars(AppId(1), 1_024), None);
+			assert_ok!(DataAvailability::set_app_block_quota(
+				root.clone(),
+				AppId(1),
+				Some(quota)
+			));
+			assert_eq!(AppBlockQuotas::<Test>::get(AppId(1)), Some(quota));
+			assert_eq!(
+				DataAvailability::app_quota_scalars(AppId(1), 1_024),
+				Some(256)
+			);
+
+			let event = RuntimeEvent::DataAvailability(Event::AppBlockQuotaSet {
+				id: AppId(1),
+				quota: Some(quota),
+			});
+			System::assert_last_event(event);
+
+			assert_ok!(DataAvailability::set_app_block_quota(root, AppId(1), None));
+			assert_eq!(AppBlockQuotas::<Test>::get(AppId(1)), None);
+		})
+	}
+
+	#[test]
+	fn absolute_quota_ignores_block_length() {
+		let quota = AppBlockQuota::Scalars(100);
+		assert_eq!(quota.max_scalars(64), 100);
+		assert_eq!(quota.max_scalars(1_024), 100);
+	}
+}
@@ -9,7 +9,7 @@ use frame_support::{
 };
 use frame_system::{CheckEra, CheckNonce, CheckWeight};
 use pallet_transaction_payment::FungibleAdapter;
-use sp_runtime::traits::{BlakeTwo256, ConstU32, IdentityLookup, TrailingZeroInput};
+use sp_runtime::traits::{BlakeTwo256, ConstU128, ConstU32, IdentityLookup, TrailingZeroInput};
 use sp_std::marker::PhantomData;
 
 pub mod custom;
@@ -31,8 +31,7 @@ pub type SignedExtra = (
 	CheckEra<Runtime>,
 	CheckNonce<Runtime>,
 	CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
-	da_control::CheckAppId<Runtime>,
+	da_control::ChargeAppTransactionPayment<Runtime>,
 );
 
 /// Provides an implementation of [`frame_support::traits::Randomness`] that should only be used in
@@ -173,7 +172,11 @@ impl pallet_balances::Config for Runtime {
 }
 
 #[derive_impl(da_control::config_preludes::TestDefaultConfig)]
-impl da_control::Config for Runtime {}
+impl da_control::Config for Runtime {
+	type AppKeyByteDeposit = ConstU128<1>;
+	type AppKeyDeposit = ConstU128<10>;
+	type Currency = Balances;
+}
 
 impl custom::custom::Config for Runtime {}
 
@@ -0,0 +1,45 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Local asset to which an Ethereum asset is bridged.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum LocalAsset<AssetId> {
+	/// The native currency.
+	Native,
+	/// An asset of the `Assets` of the pallet.
+	Fungible(AssetId),
+}
+
+/// How bridged amounts are moved on Avail.
+#[derive(
+	Clone, Copy, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub enum BridgeMode {
+	/// Sent amounts are locked into the bridge's pot, and executed messages unlock them.
+	#[default]
+	LockUnlock,
+	/// Sent amounts are burnt, and executed messages mint them. Not supported for the native
+	/// currency.
+	MintBurn,
+}
+
+/// Entry of the asset registry of the bridge.
+///
+/// The default entry bridges the native currency using lock/unlock, which is how AVAIL is
+/// bridged.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct BridgedAsset<AssetId> {
+	pub local: LocalAsset<AssetId>,
+	pub mode: BridgeMode,
+}
+
+// Implemented by hand, as deriving it would require `AssetId: Default`.
+impl<AssetId> Default for BridgedAsset<AssetId> {
+	fn default() -> Self {
+		Self {
+			local: LocalAsset::Native,
+			mode: BridgeMode::LockUnlock,
+		}
+	}
+}
@@ -1,7 +1,10 @@
 use crate::{
-	state::Configuration, BalanceOf, Call, Config, ConfigurationStorage, ExecutionStateRoots,
-	FunctionIds, FunctionInput, FunctionOutput, FunctionProof, Headers, Pallet, ProofInput,
-	PublicValuesInput, RotateVerificationKey, StepVerificationKey, Updater, ValidProof,
+	state::Configuration, AssetIdOf, BalanceOf, BridgeMode, BridgedAsset, BridgedAssets, Call,
+	Config, ConfigurationStorage, ExecutionStateRoots, FlowLimit, FlowLimits, FunctionIds,
+	FunctionInput, FunctionOutput, FunctionProof, Headers, LegacyHeadersPruning, LegacyPruning,
+	LocalAsset, NextHeaderSlot, OldestRetainedPeriod, OldestRetainedSlot, Pallet, ProofInput,
+	PublicValuesInput, RotateVerificationKey, StepVerificationKey, SyncCommitteeHashes,
+	SyncCommitteePoseidons, Timestamps, Updater, ValidProof, MAX_BATCH_MESSAGES,
 };
 use avail_core::data_proof::BOUNDED_DATA_MAX_LENGTH;
 use avail_core::data_proof::{AddressedMessage, Message};
@@ -9,12 +12,20 @@ use frame_benchmarking::{
 	impl_benchmark_test_suite, v2::benchmarks, whitelisted_caller, BenchmarkError,
 };
 use frame_support::traits::DefensiveTruncateFrom;
-use frame_support::{traits::Currency, BoundedVec};
+use frame_support::{
+	traits::{
+		fungibles::{Create, Mutate},
+		Currency,
+	},
+	weights::WeightMeter,
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 use hex_literal::hex;
+use primitive_types::U256;
 use sp_core::{Get, H256};
-use sp_runtime::traits::Bounded;
-use sp_std::vec;
+use sp_runtime::{traits::Bounded, SaturatedConversion};
+use sp_std::{vec, vec::Vec};
 
 const ACCOUNT1: [u8; 32] = [2u8; 32];
 pub const STEP_FUNCTION_ID: H256 = H256(hex!(
@@ -172,6 +183,8 @@ const SP1_VERIFICATION_KEY: [u8; 32] =
 #[benchmarks(where
 [u8; 32]: From << T as frame_system::Config >::AccountId >,
 < T as frame_system::Config >::AccountId: From < [u8; 32] >,
+T::Assets: Create< T::AccountId >,
+AssetIdOf<T>: From<u32>,
 )]
 mod benchmarks {
 	use super::*;
@@ -206,6 +219,10 @@ mod benchmarks {
 
 		// ACCOUNT1 needs to have enough funds to send 1 token
 		T::Currency::make_free_balance_be(&account, BalanceOf::<T>::max_value() / 2u32.into());
+		FlowLimits::<T>::insert(domain, H256::zero(), unreached_flow_limit());
+		// Bridging a fungible asset is more expensive than bridging the native currency.
+		let id = bridge_fungible_asset::<T>(H256::zero())?;
+		T::Assets::mint_into(id, &account, u128::MAX.saturated_into())?;
 
 		#[extrinsic_call]
 		send_message(origin, message, to, domain);
@@ -270,6 +287,14 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn destination_chain_froze() -> Result<(), BenchmarkError> {
+		#[extrinsic_call]
+		_(RawOrigin::Root, 0, true);
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn fulfill_call_step() -> Result<(), BenchmarkError> {
 		let hash = BoundedVec::truncate_from(
@@ -379,6 +404,10 @@ mod benchmarks {
 		// ACCOUNT1 needs to have enough funds to send 1 token
 		T::Currency::make_free_balance_be(&account, BalanceOf::<T>::max_value() / 2u32.into());
 		T::Currency::make_free_balance_be(&pot, BalanceOf::<T>::max_value() / 2u32.into());
+		FlowLimits::<T>::insert(2, H256::zero(), unreached_flow_limit());
+		// Bridging a fungible asset is more expensive than bridging the native currency.
+		let id = bridge_fungible_asset::<T>(H256::zero())?;
+		T::Assets::mint_into(id, &pot, u128::MAX.saturated_into())?;
 
 		let account_proof = get_valid_account_proof();
 		let storage_proof = get_valid_storage_proof();
@@ -429,6 +458,51 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn execute_batch(
+		n: Linear<1, MAX_BATCH_MESSAGES>,
+		l: Linear<0, BOUNDED_DATA_MAX_LENGTH>,
+	) -> Result<(), BenchmarkError> {
+		let hash = H256(hex!(
+			"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
+		));
+		Broadcasters::<T>::set(2, hash);
+
+		let slot = 5085118;
+
+		ExecutionStateRoots::<T>::set(
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+
+		let account = T::AccountId::from(ACCOUNT1);
+		let origin = RawOrigin::Signed(account.clone());
+
+		// Messages share the ID of the proven message with other data, so each storage proof is
+		// verified in full before the message is rejected.
+		let storage_proof = get_valid_amb_storage_proof();
+		let messages = (0..n)
+			.map(|i| {
+				let len = if i == 0 { l } else { 0 };
+				let data = BoundedVec::truncate_from(vec![1u8; len as usize]);
+				let message = AddressedMessage {
+					message: Message::ArbitraryMessage(data),
+					..get_valid_amb_message()
+				};
+				(message, storage_proof.clone())
+			})
+			.collect::<Vec<_>>();
+		let messages = BoundedVec::truncate_from(messages);
+		let account_proof = get_valid_amb_account_proof();
+
+		#[extrinsic_call]
+		_(origin, slot, 2, account_proof, messages);
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn set_function_ids() -> Result<(), BenchmarkError> {
 		let origin = RawOrigin::Root;
@@ -526,9 +600,130 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_bridged_asset() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let asset_id = H256::repeat_byte(1);
+		let asset = BridgedAsset {
+			local: LocalAsset::Native,
+			mode: BridgeMode::LockUnlock,
+		};
+
+		#[extrinsic_call]
+		_(origin, asset_id, Some(asset));
+
+		assert_eq!(BridgedAssets::<T>::get(asset_id), Some(asset));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_flow_limit() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let asset_id = H256::zero();
+		let limit = unreached_flow_limit();
+
+		#[extrinsic_call]
+		_(origin, 2, asset_id, Some(limit));
+
+		assert_eq!(FlowLimits::<T>::get(2, asset_id), Some(limit));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn prune_header() -> Result<(), BenchmarkError> {
+		let slot = 1;
+		Headers::<T>::insert(slot, H256::repeat_byte(1));
+		ExecutionStateRoots::<T>::insert(slot, H256::repeat_byte(2));
+		Timestamps::<T>::insert(slot, 1);
+		NextHeaderSlot::<T>::insert(slot, slot + 1);
+		OldestRetainedSlot::<T>::set(slot);
+		let mut budget = 1;
+		let mut meter = WeightMeter::new();
+
+		#[block]
+		{
+			Pallet::<T>::prune_headers(slot + 1, &mut budget, &mut meter);
+		}
+
+		assert_eq!(OldestRetainedSlot::<T>::get(), slot + 1);
+		assert!(!Headers::<T>::contains_key(slot));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn prune_sync_committee() -> Result<(), BenchmarkError> {
+		ConfigurationStorage::<T>::set(Configuration {
+			slots_per_period: 1,
+			finality_threshold: 1,
+		});
+		SyncCommitteePoseidons::<T>::insert(0, U256::one());
+		SyncCommitteeHashes::<T>::insert(0, H256::repeat_byte(1));
+		let mut budget = 1;
+		let mut meter = WeightMeter::new();
+
+		#[block]
+		{
+			Pallet::<T>::prune_sync_committees(1, &mut budget, &mut meter);
+		}
+
+		assert_eq!(OldestRetainedPeriod::<T>::get(), 1);
+		assert!(!SyncCommitteeHashes::<T>::contains_key(0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn prune_legacy_header() -> Result<(), BenchmarkError> {
+		let slot = 1;
+		Headers::<T>::insert(slot, H256::repeat_byte(1));
+		ExecutionStateRoots::<T>::insert(slot, H256::repeat_byte(2));
+		Timestamps::<T>::insert(slot, 1);
+		LegacyHeadersPruning::<T>::set(Some(LegacyPruning {
+			end: slot + 1,
+			cursor: None,
+		}));
+		let mut budget = 1;
+		let mut meter = WeightMeter::new();
+
+		#[block]
+		{
+			Pallet::<T>::prune_legacy_headers(slot + 1, &mut budget, &mut meter);
+		}
+
+		assert!(!Headers::<T>::contains_key(slot));
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
 
+/// Flow limit checked by bridged transfers without being crossed.
+/// Bridges `asset_id` to a new sufficient asset with lock/unlock, which moves the amounts between
+/// two asset accounts.
+fn bridge_fungible_asset<T: Config>(asset_id: H256) -> Result<AssetIdOf<T>, BenchmarkError>
+where
+	T::Assets: Create<T::AccountId>,
+	AssetIdOf<T>: From<u32>,
+{
+	let id: AssetIdOf<T> = 1u32.into();
+	T::Assets::create(id.clone(), Pallet::<T>::account_id(), true, 1u32.into())?;
+	BridgedAssets::<T>::insert(
+		asset_id,
+		BridgedAsset {
+			local: LocalAsset::Fungible(id.clone()),
+			mode: BridgeMode::LockUnlock,
+		},
+	);
+	Ok(id)
+}
+
+fn unreached_flow_limit() -> FlowLimit {
+	FlowLimit {
+		window: 100,
+		max_inflow: u128::MAX,
+		max_outflow: u128::MAX,
+	}
+}
+
 pub fn get_valid_message() -> AddressedMessage {
 	let asset_id = H256::zero();
 	let amount = 1_000_000_000_000_000_000u128;
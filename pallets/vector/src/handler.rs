@@ -0,0 +1,25 @@
+use frame_support::{dispatch::DispatchResult, weights::Weight};
+use sp_core::H256;
+
+/// Receives the arbitrary messages executed by the bridge, so that other pallets can react to
+/// messages sent from Ethereum.
+pub trait MessageHandler {
+	/// Handles `data` sent by `from` on `origin_domain` to `to` on Avail.
+	///
+	/// Changes made by a failing handler are reverted, and the message is marked as failed
+	/// instead of executed.
+	fn handle(origin_domain: u32, from: H256, to: H256, data: &[u8]) -> DispatchResult;
+
+	/// Maximum weight of handling a message of `len` bytes, charged on top of `execute`.
+	fn weight(len: u32) -> Weight;
+}
+
+impl MessageHandler for () {
+	fn handle(_origin_domain: u32, _from: H256, _to: H256, _data: &[u8]) -> DispatchResult {
+		Ok(())
+	}
+
+	fn weight(_len: u32) -> Weight {
+		Weight::zero()
+	}
+}
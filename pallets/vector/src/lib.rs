@@ -11,18 +11,28 @@ use sp1_verifier::{Groth16Verifier, GROTH16_VK_BYTES};
 use codec::Compact;
 use frame_support::{
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement, UnixTime},
+	storage::{with_storage_layer, with_transaction},
+	traits::{
+		fungibles,
+		tokens::{Fortitude, Precision, Preservation},
+		Currency, ExistenceRequirement, UnixTime,
+	},
 	PalletId,
 };
 use sp_core::H256;
-use sp_runtime::SaturatedConversion;
+use sp_runtime::{SaturatedConversion, TransactionOutcome};
 use sp_std::{vec, vec::Vec};
 
+mod assets;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 pub mod constants;
+mod handler;
+mod limits;
+pub mod migrations;
 #[cfg(test)]
 mod mock;
+mod pruning;
 mod state;
 mod storage_utils;
 #[cfg(test)]
@@ -30,7 +40,11 @@ mod tests;
 mod verifier;
 mod weights;
 
+pub use assets::{BridgeMode, BridgedAsset, LocalAsset};
+pub use handler::MessageHandler;
+pub use limits::{FlowDirection, FlowLimit, FlowUsage};
 pub use pallet::*;
+pub use pruning::LegacyPruning;
 
 sol! {
 	 struct ProofOutputs {
@@ -50,8 +64,12 @@ pub type FunctionInput = BoundedVec<u8, ConstU32<256>>;
 pub type FunctionOutput = BoundedVec<u8, ConstU32<512>>;
 pub type FunctionProof = BoundedVec<u8, ConstU32<1048>>;
 pub type ValidProof = BoundedVec<BoundedVec<u8, ConstU32<2048>>, ConstU32<32>>;
+pub type MessageBatch = BoundedVec<(AddressedMessage, ValidProof), ConstU32<MAX_BATCH_MESSAGES>>;
 
-// Avail asset is supported for now
+/// Maximum number of messages executed by `execute_batch`.
+pub const MAX_BATCH_MESSAGES: u32 = 64;
+
+// Avail asset, bridged by default using lock/unlock.
 pub const SUPPORTED_ASSET_ID: H256 = H256::zero();
 pub const FAILED_SEND_MSG_ID: &[u8] = b"vector:failed_send_msg_txs";
 pub const LOG_TARGET: &str = "runtime::vector";
@@ -60,6 +78,9 @@ pub const STEP_OUTPUT_LENGTH: u32 = 74;
 
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type AssetIdOf<T> =
+	<<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+pub type BridgedAssetOf<T> = BridgedAsset<AssetIdOf<T>>;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -134,6 +155,16 @@ pub mod pallet {
 		CannotDecodePublicValue,
 		/// Sync committee hash is already set for given period.
 		SyncCommitteeHashAlreadySet,
+		/// Given local asset does not exist.
+		UnknownLocalAsset,
+		/// Flow limit window cannot be zero.
+		InvalidFlowLimit,
+		/// Message of a batch does not originate from the batch's domain.
+		OriginDomainMismatch,
+		/// Bridge mode is not supported for the local asset.
+		UnsupportedBridgeMode,
+		/// Destination chain is frozen, messages cannot be sent to it.
+		DestinationChainFrozen,
 	}
 
 	#[pallet::event]
@@ -189,6 +220,58 @@ pub mod pallet {
 		NewSP1VerificationKey { old: H256, new: H256 },
 		/// Emit when new sync committee is updated.
 		SyncCommitteeHashUpdated { period: u64, hash: H256 },
+		/// Emit when an entry of the asset registry is set or removed.
+		BridgedAssetSet {
+			asset_id: H256,
+			asset: Option<BridgedAssetOf<T>>,
+		},
+		/// Emit when bridged amounts are locked or burnt by a sent message.
+		BridgedAssetSent {
+			asset_id: H256,
+			from: T::AccountId,
+			amount: u128,
+			mode: BridgeMode,
+		},
+		/// Emit when bridged amounts are unlocked or minted by an executed message.
+		BridgedAssetReceived {
+			asset_id: H256,
+			to: T::AccountId,
+			amount: u128,
+			mode: BridgeMode,
+		},
+		/// Emit when a flow limit is set or removed.
+		FlowLimitSet {
+			domain: u32,
+			asset_id: H256,
+			limit: Option<FlowLimit>,
+		},
+		/// Emit when a transfer was not made for crossing a flow limit, which froze the domain in
+		/// the direction of the transfer.
+		FlowLimitExceeded {
+			domain: u32,
+			asset_id: H256,
+			direction: FlowDirection,
+			amount: u128,
+		},
+		/// Emit when a message is proven but its handler fails.
+		MessageExecutionFailed {
+			from: H256,
+			to: H256,
+			message_id: u64,
+			message_root: H256,
+			error: DispatchError,
+		},
+		/// Emit when a message of a batch cannot be executed. The rest of the batch is executed.
+		BatchMessageFailed {
+			message_id: u64,
+			message_root: H256,
+			error: DispatchError,
+		},
+		/// Emit if destination chain gets frozen.
+		DestinationChainFrozen {
+			destination_chain_id: u32,
+			frozen: bool,
+		},
 	}
 
 	/// Storage for a head updates.
@@ -230,6 +313,10 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type SourceChainFrozen<T> = StorageMap<_, Identity, u32, bool, ValueQuery>;
 
+	/// Flags destination chain to be frozen.
+	#[pallet::storage]
+	pub type DestinationChainFrozen<T> = StorageMap<_, Identity, u32, bool, ValueQuery>;
+
 	/// List of permitted domains.
 	#[pallet::storage]
 	pub type WhitelistedDomains<T> = StorageValue<_, BoundedVec<u32, ConstU32<10_000>>, ValueQuery>;
@@ -290,6 +377,37 @@ pub mod pallet {
 	#[pallet::getter(fn sp1_verification_key)]
 	pub type SP1VerificationKey<T: Config> = StorageValue<_, H256, ValueQuery>;
 
+	/// Maps Ethereum asset ids to the local assets they are bridged to.
+	/// AVAIL (`SUPPORTED_ASSET_ID`) uses the default entry unless it is set here.
+	#[pallet::storage]
+	pub type BridgedAssets<T: Config> = StorageMap<_, Identity, H256, BridgedAssetOf<T>>;
+
+	/// Limits of the value of an asset bridged through a domain.
+	/// Crossing one of them freezes the domain.
+	#[pallet::storage]
+	pub type FlowLimits<T> = StorageDoubleMap<_, Identity, u32, Identity, H256, FlowLimit>;
+
+	/// Value of an asset bridged through a domain during the current and previous windows.
+	#[pallet::storage]
+	pub type FlowUsages<T> =
+		StorageDoubleMap<_, Identity, u32, Identity, H256, FlowUsage, ValueQuery>;
+
+	/// Slot of the header stored after the one of each slot, followed when pruning.
+	#[pallet::storage]
+	pub type NextHeaderSlot<T> = StorageMap<_, Identity, u64, u64>;
+
+	/// Low-water mark of the headers: the oldest slot whose header has not been pruned.
+	#[pallet::storage]
+	pub type OldestRetainedSlot<T> = StorageValue<_, u64, ValueQuery>;
+
+	/// Low-water mark of the sync committees: the oldest period which has not been pruned.
+	#[pallet::storage]
+	pub type OldestRetainedPeriod<T> = StorageValue<_, u64, ValueQuery>;
+
+	/// Sweep of the headers stored before `NextHeaderSlot` was introduced, set by the migration.
+	#[pallet::storage]
+	pub type LegacyHeadersPruning<T> = StorageValue<_, LegacyPruning>;
+
 	/// Default implementations of [`DefaultConfig`], which can be used to implement [`Config`].
 	pub mod config_preludes {
 		use super::*;
@@ -320,6 +438,9 @@ pub mod pallet {
 			#[inject_runtime_type]
 			type RuntimeCall = ();
 			type PalletId = BridgePalletId;
+			type MessageHandler = ();
+			type RetainedSlots = ConstU64<{ 8192 * 64 }>;
+			type MaxPrunedPerBlock = ConstU32<32>;
 		}
 	}
 
@@ -338,6 +459,9 @@ pub mod pallet {
 		/// Currency type for this pallet.
 		#[pallet::no_default]
 		type Currency: LockableCurrency<Self::AccountId, Moment = BlockNumberFor<Self>>;
+		/// Fungible assets which Ethereum assets can be bridged to, besides the native currency.
+		#[pallet::no_default]
+		type Assets: fungibles::Mutate<Self::AccountId>;
 		/// Dependency that can provide current time.
 		#[pallet::no_default]
 		type TimeProvider: UnixTime;
@@ -351,6 +475,15 @@ pub mod pallet {
 		/// Unique value associated with Avail Network. Used to distinguish messages between Avail and non-Avail networks.
 		#[pallet::constant]
 		type AvailDomain: Get<u32>;
+		/// Receives the executed arbitrary messages.
+		type MessageHandler: MessageHandler;
+		/// Number of slots behind the head for which the light client state is kept.
+		/// Messages proven against older slots can no longer be executed.
+		#[pallet::constant]
+		type RetainedSlots: Get<u64>;
+		/// Maximum number of slots or periods pruned in a block.
+		#[pallet::constant]
+		type MaxPrunedPerBlock: Get<u32>;
 	}
 
 	#[pallet::genesis_config]
@@ -416,7 +549,11 @@ pub mod pallet {
 		}
 	}
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -430,6 +567,10 @@ pub mod pallet {
 
 			Weight::zero()
 		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::prune(remaining_weight)
+		}
 	}
 
 	#[pallet::call]
@@ -507,7 +648,8 @@ pub mod pallet {
 		#[pallet::call_index(1)]
 		#[pallet::weight({
 			match addr_message.message {
-				Message::ArbitraryMessage(ref data) => T::WeightInfo::execute_arbitrary_message(data.len() as u32),
+				Message::ArbitraryMessage(ref data) => T::WeightInfo::execute_arbitrary_message(data.len() as u32)
+					.saturating_add(T::MessageHandler::weight(data.len() as u32)),
 				Message::FungibleToken {..} => T::WeightInfo::execute_fungible_token(),
 			}
 		})]
@@ -523,64 +665,9 @@ pub mod pallet {
 			let message_root = H256(keccak_256(encoded_data.as_slice()));
 
 			Self::check_preconditions(&addr_message, message_root)?;
-
-			ensure!(
-				!SourceChainFrozen::<T>::get(addr_message.origin_domain),
-				Error::<T>::SourceChainFrozen
-			);
-			let root = ExecutionStateRoots::<T>::get(slot);
-			let broadcaster = Broadcasters::<T>::get(addr_message.origin_domain);
-
-			// extract contract address
-			let contract_broadcaster_address = H160::from_slice(broadcaster[..20].as_ref());
-			let account_proof_vec = account_proof
-				.iter()
-				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
-				.collect();
-
 			let storage_root =
-				get_storage_root(account_proof_vec, contract_broadcaster_address, root)
-					.map_err(|_| Error::<T>::CannotGetStorageRoot)?;
-
-			let message_id = Uint(U256::from(addr_message.id));
-			let mm_idx = Uint(U256::from(T::MessageMappingStorageIndex::get()));
-			let slot_key = H256(keccak_256(ethabi::encode(&[message_id, mm_idx]).as_slice()));
-
-			let storage_proof_vec = storage_proof
-				.iter()
-				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
-				.collect();
-
-			let slot_value = get_storage_value(slot_key, storage_root, storage_proof_vec)
-				.map_err(|_| Error::<T>::CannotGetStorageValue)?;
-
-			ensure!(slot_value == message_root, Error::<T>::InvalidMessageHash);
-
-			if let Message::FungibleToken { asset_id, amount } = &addr_message.message {
-				ensure!(
-					SUPPORTED_ASSET_ID == *asset_id,
-					Error::<T>::AssetNotSupported
-				);
-
-				let destination_account_id =
-					T::AccountId::decode(&mut &addr_message.to.encode()[..])
-						.map_err(|_| Error::<T>::CannotDecodeDestinationAccountId)?;
-
-				T::Currency::transfer(
-					&Self::account_id(),
-					&destination_account_id,
-					(*amount).saturated_into(),
-					ExistenceRequirement::AllowDeath,
-				)?;
-			}
-
-			MessageStatus::<T>::set(message_root, MessageStatusEnum::ExecutionSucceeded);
-			Self::deposit_event(Event::<T>::MessageExecuted {
-				from: addr_message.from,
-				to: addr_message.to,
-				message_id: addr_message.id,
-				message_root,
-			});
+				Self::broadcaster_storage_root(slot, addr_message.origin_domain, account_proof)?;
+			Self::execute_message(addr_message, message_root, storage_root, storage_proof)?;
 
 			Ok(().into())
 		}
@@ -628,24 +715,13 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			let dispatch = Self::do_send_message(who, message, to, domain);
-			if dispatch.is_err() {
-				let _ = MemoryTemporaryStorage::update::<Vec<Compact<u32>>, _>(
-					FAILED_SEND_MSG_ID.to_vec(),
-					|failed| {
-						let tx_idx_result = <frame_system::Pallet<T>>::extrinsic_index();
-						// this should never happen and we can just log warn
-						if tx_idx_result.is_none() {
-							log::warn!(target: LOG_TARGET, "Transaction index is none!");
-						}
-						let tx_idx = tx_idx_result.unwrap_or_default();
-						failed.push(tx_idx.into());
-						log::trace!(target: LOG_TARGET, "Send Message failed txs: {failed:?}");
-					},
-				);
+			// Messages not sent for crossing a flow limit are not bridged either.
+			let sent = Self::do_send_message(who, message, to, domain);
+			if !matches!(sent, Ok(true)) {
+				Self::note_failed_send_message();
 			}
 
-			dispatch
+			sent.map(|_| ().into()).map_err(Into::into)
 		}
 
 		/// set_poseidon_hash sets poseidon hash of the sync committee for the particular period.
@@ -852,6 +928,7 @@ pub mod pallet {
 			ensure!(is_valid.is_ok(), Error::<T>::VerificationFailed);
 
 			Head::<T>::set(new_head);
+			NextHeaderSlot::<T>::insert(head, new_head);
 			let header = Headers::<T>::get(new_head);
 			ensure!(header == H256::zero(), Error::<T>::HeaderRootAlreadySet);
 
@@ -942,38 +1019,182 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// set_bridged_asset sets or removes the local asset an Ethereum asset is bridged to.
+		//
+		// Test names: set_bridged_asset_works_with_root(), set_bridged_asset_does_not_work_with_non_root(),
+		//	set_bridged_asset_does_not_accept_unknown_asset(),
+		//	set_bridged_asset_does_not_accept_native_mint_burn()
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::set_bridged_asset())]
+		pub fn set_bridged_asset(
+			origin: OriginFor<T>,
+			asset_id: H256,
+			asset: Option<BridgedAssetOf<T>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			match asset {
+				Some(BridgedAsset {
+					local: LocalAsset::Native,
+					mode: BridgeMode::MintBurn,
+				}) => return Err(Error::<T>::UnsupportedBridgeMode.into()),
+				Some(BridgedAsset {
+					local: LocalAsset::Fungible(id),
+					..
+				}) => ensure!(
+					<T::Assets as fungibles::Inspect<_>>::asset_exists(id),
+					Error::<T>::UnknownLocalAsset
+				),
+				_ => {},
+			}
+
+			BridgedAssets::<T>::set(asset_id, asset);
+			Self::deposit_event(Event::BridgedAssetSet { asset_id, asset });
+
+			Ok(())
+		}
+
+		/// Sets or removes the limits of the value of `asset_id` bridged through `domain`.
+		/// Usage is reset, so the new limits apply from the current block.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::set_flow_limit())]
+		pub fn set_flow_limit(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			asset_id: H256,
+			limit: Option<FlowLimit>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			if let Some(limit) = limit {
+				ensure!(limit.window > 0, Error::<T>::InvalidFlowLimit);
+			}
+
+			FlowLimits::<T>::set(domain, asset_id, limit);
+			FlowUsages::<T>::remove(domain, asset_id);
+			Self::deposit_event(Event::FlowLimitSet {
+				domain,
+				asset_id,
+				limit,
+			});
+
+			Ok(())
+		}
+
+		/// Executes messages of `domain` proven against a single account proof of its broadcaster.
+		/// Messages that cannot be executed are reported in events, without failing the batch.
+		///
+		/// The weight of the messages which are not executed is refunded.
+		#[pallet::call_index(18)]
+		#[pallet::weight({
+			messages.iter().fold(
+				T::WeightInfo::execute_batch(messages.len() as u32, Pallet::<T>::batch_data_len(messages)),
+				|weight, (addr_message, _)| weight.saturating_add(Pallet::<T>::batch_message_weight(&addr_message.message)),
+			)
+		})]
+		pub fn execute_batch(
+			origin: OriginFor<T>,
+			#[pallet::compact] slot: u64,
+			#[pallet::compact] domain: u32,
+			account_proof: ValidProof,
+			messages: MessageBatch,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(!messages.is_empty(), Error::<T>::InvalidBridgeInputs);
+			let storage_root = Self::broadcaster_storage_root(slot, domain, account_proof)?;
+
+			let mut actual_weight = T::WeightInfo::execute_batch(
+				messages.len() as u32,
+				Self::batch_data_len(&messages),
+			);
+
+			for (addr_message, storage_proof) in messages {
+				let encoded_data = addr_message.clone().abi_encode();
+				let message_root = H256(keccak_256(encoded_data.as_slice()));
+				let message_id = addr_message.id;
+				let message_weight = Self::batch_message_weight(&addr_message.message);
+
+				let executed = with_storage_layer(|| {
+					ensure!(
+						addr_message.origin_domain == domain,
+						Error::<T>::OriginDomainMismatch
+					);
+					Self::check_preconditions(&addr_message, message_root)?;
+					Self::execute_message(addr_message, message_root, storage_root, storage_proof)
+				});
+
+				match executed {
+					Ok(()) => actual_weight.saturating_accrue(message_weight),
+					Err(error) => Self::deposit_event(Event::<T>::BatchMessageFailed {
+						message_id,
+						message_root,
+						error,
+					}),
+				}
+			}
+
+			Ok(Some(actual_weight).into())
+		}
+
+		/// destination_chain_froze froze destination chain and prevent messages to be sent to it.
+		//
+		// Test names: destination_chain_froze_works_with_root(), destination_chain_froze_does_not_work_with_non_root()
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::destination_chain_froze())]
+		pub fn destination_chain_froze(
+			origin: OriginFor<T>,
+			#[pallet::compact] destination_chain_id: u32,
+			frozen: bool,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			DestinationChainFrozen::<T>::set(destination_chain_id, frozen);
+			Self::deposit_event(Event::<T>::DestinationChainFrozen {
+				destination_chain_id,
+				frozen,
+			});
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Sends `message` to `domain`, returning `false` if it was not sent for crossing a flow
+		/// limit.
 		fn do_send_message(
 			who: T::AccountId,
 			message: Message,
 			to: H256,
 			domain: u32,
-		) -> DispatchResultWithPostInfo {
+		) -> Result<bool, DispatchError> {
 			// Ensure the domain is currently supported
 			ensure!(
 				Self::is_domain_valid(domain),
 				Error::<T>::DomainNotSupported
 			);
+			ensure!(
+				!Self::is_frozen(domain, FlowDirection::Outflow),
+				Error::<T>::DestinationChainFrozen
+			);
 			// Check MessageType and enforce the rules
 			let message_type = message.r#type();
 			match message {
 				Message::FungibleToken { asset_id, amount } => {
-					ensure!(
-						SUPPORTED_ASSET_ID == asset_id,
-						Error::<T>::AssetNotSupported
-					);
 					ensure!(
 						amount.saturated_into::<u128>() > 0,
 						Error::<T>::InvalidBridgeInputs
 					);
-					T::Currency::transfer(
-						&who,
-						&Self::account_id(),
-						amount.saturated_into(),
-						ExistenceRequirement::KeepAlive,
+					let sent = Self::transfer_within_flow_limit(
+						domain,
+						asset_id,
+						amount,
+						FlowDirection::Outflow,
+						|| Self::send_asset(asset_id, &who, amount),
 					)?;
+					if !sent {
+						return Ok(false);
+					}
 				},
 				Message::ArbitraryMessage(data) => {
 					ensure!(!data.is_empty(), Error::<T>::InvalidBridgeInputs)
@@ -990,7 +1211,243 @@ pub mod pallet {
 				message_id,
 			});
 
-			Ok(().into())
+			Ok(true)
+		}
+
+		/// Records the current transaction as a failed `send_message`.
+		fn note_failed_send_message() {
+			let _ = MemoryTemporaryStorage::update::<Vec<Compact<u32>>, _>(
+				FAILED_SEND_MSG_ID.to_vec(),
+				|failed| {
+					let tx_idx_result = <frame_system::Pallet<T>>::extrinsic_index();
+					// this should never happen and we can just log warn
+					if tx_idx_result.is_none() {
+						log::warn!(target: LOG_TARGET, "Transaction index is none!");
+					}
+					let tx_idx = tx_idx_result.unwrap_or_default();
+					failed.push(tx_idx.into());
+					log::trace!(target: LOG_TARGET, "Send Message failed txs: {failed:?}");
+				},
+			);
+		}
+
+		/// Moves `amount` of `asset_id` with `transfer`, accounting it against the flow limit of
+		/// `domain`.
+		///
+		/// If the amount would cross the limit, the transfer is reverted, the domain is frozen in
+		/// that direction and `false` is returned. Failed transfers are not accounted, so they
+		/// never freeze the domain.
+		pub(crate) fn transfer_within_flow_limit(
+			domain: u32,
+			asset_id: H256,
+			amount: u128,
+			direction: FlowDirection,
+			transfer: impl FnOnce() -> DispatchResult,
+		) -> Result<bool, DispatchError> {
+			let within_limit = with_transaction(|| {
+				if let Err(e) = transfer() {
+					return TransactionOutcome::Rollback(Err(e));
+				}
+				if Self::note_flow(domain, asset_id, amount, direction) {
+					TransactionOutcome::Commit(Ok(true))
+				} else {
+					TransactionOutcome::Rollback(Ok(false))
+				}
+			})?;
+
+			if !within_limit {
+				log::warn!(
+					target: LOG_TARGET,
+					"Flow limit of {asset_id:?} crossed on domain {domain}, freezing it"
+				);
+				Self::freeze_on_flow_limit(domain, asset_id, direction, amount);
+			}
+			Ok(within_limit)
+		}
+
+		/// Accounts `amount` of `asset_id` bridged through `domain` against its flow limit.
+		///
+		/// Returns `false`, without accounting it, if the amount would cross the limit.
+		pub(crate) fn note_flow(
+			domain: u32,
+			asset_id: H256,
+			amount: u128,
+			direction: FlowDirection,
+		) -> bool {
+			let Some(limit) = FlowLimits::<T>::get(domain, asset_id) else {
+				return true;
+			};
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			let mut usage = FlowUsages::<T>::get(domain, asset_id);
+			usage.roll(now, limit.window);
+
+			let max = match direction {
+				FlowDirection::Inflow => limit.max_inflow,
+				FlowDirection::Outflow => limit.max_outflow,
+			};
+			let used = usage.rolling(direction, now, limit.window);
+			if used.saturating_add(amount) > max {
+				return false;
+			}
+
+			usage.note(direction, amount);
+			FlowUsages::<T>::insert(domain, asset_id, usage);
+			true
+		}
+
+		/// Whether messages cannot be executed from (`Inflow`) or sent to (`Outflow`) `domain`.
+		pub(crate) fn is_frozen(domain: u32, direction: FlowDirection) -> bool {
+			match direction {
+				FlowDirection::Inflow => SourceChainFrozen::<T>::get(domain),
+				FlowDirection::Outflow => DestinationChainFrozen::<T>::get(domain),
+			}
+		}
+
+		/// Freezes `domain` in the direction of a transfer of `amount` crossing its flow limit.
+		fn freeze_on_flow_limit(
+			domain: u32,
+			asset_id: H256,
+			direction: FlowDirection,
+			amount: u128,
+		) {
+			match direction {
+				FlowDirection::Inflow => {
+					SourceChainFrozen::<T>::set(domain, true);
+					Self::deposit_event(Event::SourceChainFrozen {
+						source_chain_id: domain,
+						frozen: true,
+					});
+				},
+				FlowDirection::Outflow => {
+					DestinationChainFrozen::<T>::set(domain, true);
+					Self::deposit_event(Event::DestinationChainFrozen {
+						destination_chain_id: domain,
+						frozen: true,
+					});
+				},
+			}
+			Self::deposit_event(Event::FlowLimitExceeded {
+				domain,
+				asset_id,
+				direction,
+				amount,
+			});
+		}
+
+		/// Total length of the arbitrary messages of a batch.
+		pub(crate) fn batch_data_len(messages: &MessageBatch) -> u32 {
+			messages
+				.iter()
+				.fold(0u32, |len, (addr_message, _)| match addr_message.message {
+					Message::ArbitraryMessage(ref data) => len.saturating_add(data.len() as u32),
+					Message::FungibleToken { .. } => len,
+				})
+		}
+
+		/// Weight of executing `message` in a batch, on top of the verification of its storage
+		/// proof which is covered by the `execute_batch` benchmark.
+		pub(crate) fn batch_message_weight(message: &Message) -> Weight {
+			match message {
+				Message::ArbitraryMessage(data) => T::MessageHandler::weight(data.len() as u32),
+				Message::FungibleToken { .. } => T::WeightInfo::execute_fungible_token()
+					.saturating_sub(T::WeightInfo::execute_arbitrary_message(0)),
+			}
+		}
+
+		/// Returns the registry entry of `asset_id`, AVAIL being bridged by default.
+		pub fn bridged_asset(asset_id: H256) -> Option<BridgedAssetOf<T>> {
+			BridgedAssets::<T>::get(asset_id)
+				.or_else(|| (asset_id == SUPPORTED_ASSET_ID).then(BridgedAsset::default))
+		}
+
+		/// Locks or burns `amount` of the local asset of `asset_id` from `who`.
+		pub(crate) fn send_asset(
+			asset_id: H256,
+			who: &T::AccountId,
+			amount: u128,
+		) -> DispatchResult {
+			let asset = Self::bridged_asset(asset_id).ok_or(Error::<T>::AssetNotSupported)?;
+			match (asset.local, asset.mode) {
+				(LocalAsset::Native, BridgeMode::LockUnlock) => {
+					T::Currency::transfer(
+						who,
+						&Self::account_id(),
+						amount.saturated_into(),
+						ExistenceRequirement::KeepAlive,
+					)?;
+				},
+				(LocalAsset::Native, BridgeMode::MintBurn) => {
+					return Err(Error::<T>::UnsupportedBridgeMode.into())
+				},
+				(LocalAsset::Fungible(id), BridgeMode::LockUnlock) => {
+					<T::Assets as fungibles::Mutate<_>>::transfer(
+						id,
+						who,
+						&Self::account_id(),
+						amount.saturated_into(),
+						Preservation::Preserve,
+					)?;
+				},
+				(LocalAsset::Fungible(id), BridgeMode::MintBurn) => {
+					T::Assets::burn_from(
+						id,
+						who,
+						amount.saturated_into(),
+						Precision::Exact,
+						Fortitude::Polite,
+					)?;
+				},
+			}
+
+			Self::deposit_event(Event::BridgedAssetSent {
+				asset_id,
+				from: who.clone(),
+				amount,
+				mode: asset.mode,
+			});
+			Ok(())
+		}
+
+		/// Unlocks or mints `amount` of the local asset of `asset_id` to `to`.
+		pub(crate) fn receive_asset(
+			asset_id: H256,
+			to: &T::AccountId,
+			amount: u128,
+		) -> DispatchResult {
+			let asset = Self::bridged_asset(asset_id).ok_or(Error::<T>::AssetNotSupported)?;
+			match (asset.local, asset.mode) {
+				(LocalAsset::Native, BridgeMode::LockUnlock) => {
+					T::Currency::transfer(
+						&Self::account_id(),
+						to,
+						amount.saturated_into(),
+						ExistenceRequirement::AllowDeath,
+					)?;
+				},
+				(LocalAsset::Native, BridgeMode::MintBurn) => {
+					return Err(Error::<T>::UnsupportedBridgeMode.into())
+				},
+				(LocalAsset::Fungible(id), BridgeMode::LockUnlock) => {
+					<T::Assets as fungibles::Mutate<_>>::transfer(
+						id,
+						&Self::account_id(),
+						to,
+						amount.saturated_into(),
+						Preservation::Expendable,
+					)?;
+				},
+				(LocalAsset::Fungible(id), BridgeMode::MintBurn) => {
+					T::Assets::mint_into(id, to, amount.saturated_into())?;
+				},
+			}
+
+			Self::deposit_event(Event::BridgedAssetReceived {
+				asset_id,
+				to: to.clone(),
+				amount,
+				mode: asset.mode,
+			});
+			Ok(())
 		}
 
 		fn fetch_curr_message_id() -> Result<u64, DispatchError> {
@@ -1003,6 +1460,102 @@ pub mod pallet {
 			}
 		}
 
+		/// Verifies the account proof of the broadcaster of `domain` against the execution state
+		/// root of `slot`, and returns the broadcaster's storage root.
+		fn broadcaster_storage_root(
+			slot: u64,
+			domain: u32,
+			account_proof: ValidProof,
+		) -> Result<H256, DispatchError> {
+			let root = ExecutionStateRoots::<T>::get(slot);
+			let broadcaster = Broadcasters::<T>::get(domain);
+
+			// extract contract address
+			let contract_broadcaster_address = H160::from_slice(broadcaster[..20].as_ref());
+			let account_proof_vec = account_proof
+				.iter()
+				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
+				.collect();
+
+			get_storage_root(account_proof_vec, contract_broadcaster_address, root)
+				.map_err(|_| Error::<T>::CannotGetStorageRoot.into())
+		}
+
+		/// Executes `addr_message`, proven by `storage_proof` against the broadcaster's
+		/// `storage_root`. Preconditions must have been checked.
+		fn execute_message(
+			addr_message: AddressedMessage,
+			message_root: H256,
+			storage_root: H256,
+			storage_proof: ValidProof,
+		) -> DispatchResult {
+			let message_id = Uint(U256::from(addr_message.id));
+			let mm_idx = Uint(U256::from(T::MessageMappingStorageIndex::get()));
+			let slot_key = H256(keccak_256(ethabi::encode(&[message_id, mm_idx]).as_slice()));
+
+			let storage_proof_vec = storage_proof
+				.iter()
+				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
+				.collect();
+
+			let slot_value = get_storage_value(slot_key, storage_root, storage_proof_vec)
+				.map_err(|_| Error::<T>::CannotGetStorageValue)?;
+
+			ensure!(slot_value == message_root, Error::<T>::InvalidMessageHash);
+
+			if let Message::FungibleToken { asset_id, amount } = &addr_message.message {
+				let destination_account_id =
+					T::AccountId::decode(&mut &addr_message.to.encode()[..])
+						.map_err(|_| Error::<T>::CannotDecodeDestinationAccountId)?;
+
+				let received = Self::transfer_within_flow_limit(
+					addr_message.origin_domain,
+					*asset_id,
+					*amount,
+					FlowDirection::Inflow,
+					|| Self::receive_asset(*asset_id, &destination_account_id, *amount),
+				)?;
+				// The message is left unexecuted, so it can be retried once the domain is unfrozen.
+				if !received {
+					return Ok(());
+				}
+			}
+
+			if let Message::ArbitraryMessage(data) = &addr_message.message {
+				let handled = with_storage_layer(|| {
+					T::MessageHandler::handle(
+						addr_message.origin_domain,
+						addr_message.from,
+						addr_message.to,
+						data,
+					)
+				});
+
+				// The failure is recorded rather than reverted, so the message is not executed again.
+				if let Err(error) = handled {
+					MessageStatus::<T>::set(message_root, MessageStatusEnum::ExecutionFailed);
+					Self::deposit_event(Event::<T>::MessageExecutionFailed {
+						from: addr_message.from,
+						to: addr_message.to,
+						message_id: addr_message.id,
+						message_root,
+						error,
+					});
+					return Ok(());
+				}
+			}
+
+			MessageStatus::<T>::set(message_root, MessageStatusEnum::ExecutionSucceeded);
+			Self::deposit_event(Event::<T>::MessageExecuted {
+				from: addr_message.from,
+				to: addr_message.to,
+				message_id: addr_message.id,
+				message_root,
+			});
+
+			Ok(())
+		}
+
 		fn check_preconditions(
 			message: &AddressedMessage,
 			message_root: H256,
@@ -1030,6 +1583,11 @@ pub mod pallet {
 				Error::<T>::BroadcasterSourceChainNotSet
 			);
 
+			ensure!(
+				!Self::is_frozen(message.origin_domain, FlowDirection::Inflow),
+				Error::<T>::SourceChainFrozen
+			);
+
 			Ok(())
 		}
 
@@ -1105,6 +1663,7 @@ pub mod pallet {
 				Error::<T>::StateRootAlreadySet
 			);
 
+			NextHeaderSlot::<T>::insert(Head::<T>::get(), step_output.finalized_slot);
 			Head::<T>::set(step_output.finalized_slot);
 			Headers::<T>::insert(
 				step_output.finalized_slot,
@@ -0,0 +1,82 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{Perbill, RuntimeDebug};
+
+/// Direction of the value bridged through a domain.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum FlowDirection {
+	/// Executed messages, received on Avail.
+	Inflow,
+	/// Sent messages, leaving Avail.
+	Outflow,
+}
+
+/// Maximum value of an asset bridged through a domain over a rolling window of blocks.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FlowLimit {
+	/// Length of the window, in blocks.
+	pub window: u32,
+	/// Maximum value executed from the domain during the window.
+	pub max_inflow: u128,
+	/// Maximum value sent to the domain during the window.
+	pub max_outflow: u128,
+}
+
+/// Value bridged during the current window and the one before it.
+#[derive(
+	Clone, Copy, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct FlowUsage {
+	/// First block of the current window.
+	pub window_start: u32,
+	pub inflow: u128,
+	pub outflow: u128,
+	pub prev_inflow: u128,
+	pub prev_outflow: u128,
+}
+
+impl FlowUsage {
+	/// Moves the current window forward so that it contains block `now`.
+	pub fn roll(&mut self, now: u32, window: u32) {
+		let elapsed = now.saturating_sub(self.window_start);
+		if window == 0 || elapsed < window {
+			return;
+		}
+
+		if elapsed < window.saturating_mul(2) {
+			self.prev_inflow = self.inflow;
+			self.prev_outflow = self.outflow;
+			self.window_start = self.window_start.saturating_add(window);
+		} else {
+			self.prev_inflow = 0;
+			self.prev_outflow = 0;
+			self.window_start = now.saturating_sub(elapsed % window);
+		}
+		self.inflow = 0;
+		self.outflow = 0;
+	}
+
+	/// Value bridged over the last `window` blocks up to `now`.
+	///
+	/// The previous window is weighted by how much it overlaps with them, which assumes its
+	/// value was bridged evenly.
+	pub fn rolling(&self, direction: FlowDirection, now: u32, window: u32) -> u128 {
+		let (current, prev) = match direction {
+			FlowDirection::Inflow => (self.inflow, self.prev_inflow),
+			FlowDirection::Outflow => (self.outflow, self.prev_outflow),
+		};
+		let overlap = window.saturating_sub(now.saturating_sub(self.window_start));
+		let prev = Perbill::from_rational(overlap, window.max(1)).mul_floor(prev);
+
+		current.saturating_add(prev)
+	}
+
+	/// Accounts `amount` in the current window.
+	pub fn note(&mut self, direction: FlowDirection, amount: u128) {
+		let flow = match direction {
+			FlowDirection::Inflow => &mut self.inflow,
+			FlowDirection::Outflow => &mut self.outflow,
+		};
+		*flow = flow.saturating_add(amount);
+	}
+}
@@ -0,0 +1,61 @@
+//! Storage migrations of `pallet_vector`.
+
+pub mod v1 {
+	use crate::{
+		Config, Head, LegacyHeadersPruning, LegacyPruning, OldestRetainedSlot, Pallet, LOG_TARGET,
+	};
+	use frame_support::{
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+		weights::Weight,
+	};
+	use sp_std::marker::PhantomData;
+	#[cfg(feature = "try-runtime")]
+	use {sp_runtime::TryRuntimeError, sp_std::vec::Vec};
+
+	/// Starts pruning the light client state.
+	///
+	/// Headers stored from now on are linked by `NextHeaderSlot`, starting at the current head.
+	/// Older ones are swept in chunks by `on_idle`, as there are too many to be removed here.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 0 {
+				log::info!(
+					target: LOG_TARGET,
+					"Skipping light client pruning migration, on-chain storage version is {on_chain:?}"
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let head = Head::<T>::get();
+			OldestRetainedSlot::<T>::set(head);
+			LegacyHeadersPruning::<T>::set(Some(LegacyPruning {
+				end: head,
+				cursor: None,
+			}));
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: LOG_TARGET, "Scheduled pruning of headers older than slot {head}");
+			T::DbWeight::get().reads_writes(2, 3)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			frame_support::ensure!(
+				OldestRetainedSlot::<T>::get() == Head::<T>::get(),
+				"Low-water mark is not the head"
+			);
+			frame_support::ensure!(
+				LegacyHeadersPruning::<T>::exists(),
+				"Legacy headers are not scheduled for pruning"
+			);
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"Storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
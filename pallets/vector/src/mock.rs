@@ -1,10 +1,19 @@
-use frame_support::{derive_impl, parameter_types, traits::ConstU64, PalletId};
-use frame_system::{native::hosted_header_builder::da, test_utils::TestRandomness};
+use frame_support::{
+	derive_impl,
+	dispatch::DispatchResult,
+	parameter_types,
+	traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64},
+	weights::Weight,
+	PalletId,
+};
+use frame_system::{
+	native::hosted_header_builder::da, test_utils::TestRandomness, EnsureRoot, EnsureSigned,
+};
 use hex_literal::hex;
 use primitive_types::H256;
 use sp_runtime::{
 	traits::{Block as BlockT, IdentityLookup},
-	AccountId32, BuildStorage,
+	AccountId32, BuildStorage, DispatchError,
 };
 
 use crate as vector_bridge;
@@ -167,6 +176,7 @@ frame_support::construct_runtime!(
 		System: frame_system,
 		Timestamp: pallet_timestamp,
 		Balances: pallet_balances,
+		Assets: pallet_assets,
 		Bridge: vector_bridge,
 	}
 );
@@ -201,6 +211,29 @@ impl pallet_balances::Config for Test {
 	type ExistentialDeposit = ExistentialDeposit;
 }
 
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId32>>;
+	type ForceOrigin = EnsureRoot<AccountId32>;
+	type AssetDeposit = ConstU128<1>;
+	type AssetAccountDeposit = ConstU128<1>;
+	type MetadataDepositBase = ConstU128<1>;
+	type MetadataDepositPerByte = ConstU128<1>;
+	type ApprovalDeposit = ConstU128<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	type RemoveItemsLimit = ConstU32<1000>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
 impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = ();
@@ -210,12 +243,38 @@ impl pallet_timestamp::Config for Test {
 
 parameter_types! {
 	pub const BridgePalletId: PalletId = PalletId(*b"avl/brdg");
+	pub storage HandledMessages: Vec<(u32, H256, H256, Vec<u8>)> = vec![];
+	pub storage FailHandler: bool = false;
+}
+
+/// Records the messages it handles, and then fails if `FailHandler` is set.
+pub struct MockMessageHandler;
+
+impl vector_bridge::MessageHandler for MockMessageHandler {
+	fn handle(origin_domain: u32, from: H256, to: H256, data: &[u8]) -> DispatchResult {
+		let mut handled = HandledMessages::get();
+		handled.push((origin_domain, from, to, data.to_vec()));
+		HandledMessages::set(&handled);
+
+		if FailHandler::get() {
+			return Err(DispatchError::Other("Handler failed"));
+		}
+		Ok(())
+	}
+
+	fn weight(len: u32) -> Weight {
+		Weight::from_parts(len.into(), 0)
+	}
 }
 
 #[derive_impl(crate::config_preludes::TestDefaultConfig as crate::DefaultConfig)]
 impl vector_bridge::Config for Test {
 	type TimeProvider = Timestamp;
 	type Currency = Balances;
+	type Assets = Assets;
+	type MessageHandler = MockMessageHandler;
+	type RetainedSlots = ConstU64<100>;
+	type MaxPrunedPerBlock = ConstU32<3>;
 }
 
 /// Create new externalities for `Vector` module tests.
@@ -0,0 +1,134 @@
+use crate::{
+	Config, ConfigurationStorage, ExecutionStateRoots, Head, Headers, LegacyHeadersPruning,
+	NextHeaderSlot, OldestRetainedPeriod, OldestRetainedSlot, Pallet, SyncCommitteeHashes,
+	SyncCommitteePoseidons, Timestamps, WeightInfo,
+};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	traits::{ConstU32, DefensiveTruncateFrom, Get},
+	weights::{Weight, WeightMeter},
+	BoundedVec,
+};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Sweep of the headers stored before they were linked by `NextHeaderSlot`.
+///
+/// These headers are not reachable from the low-water mark, so they are found by iterating
+/// `Headers` in chunks. The sweep starts once every legacy slot is outside the retention window,
+/// so that a single pass removes all of them.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct LegacyPruning {
+	/// Head when the sweep was scheduled. Older slots are legacy.
+	pub end: u64,
+	/// Raw key of `Headers` after which the pass continues.
+	pub cursor: Option<BoundedVec<u8, ConstU32<64>>>,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Prunes the light client state older than `RetainedSlots` behind the head, using at most
+	/// `remaining` weight and `MaxPrunedPerBlock` entries.
+	pub(crate) fn prune(remaining: Weight) -> Weight {
+		let mut meter = WeightMeter::with_limit(remaining);
+		if meter
+			.try_consume(T::DbWeight::get().reads_writes(5, 3))
+			.is_err()
+		{
+			return Weight::zero();
+		}
+
+		let cutoff = Head::<T>::get().saturating_sub(T::RetainedSlots::get());
+		let mut budget = T::MaxPrunedPerBlock::get();
+
+		Self::prune_headers(cutoff, &mut budget, &mut meter);
+		Self::prune_sync_committees(cutoff, &mut budget, &mut meter);
+		Self::prune_legacy_headers(cutoff, &mut budget, &mut meter);
+
+		meter.consumed()
+	}
+
+	/// Follows `NextHeaderSlot` from the low-water mark up to `cutoff`.
+	pub(crate) fn prune_headers(cutoff: u64, budget: &mut u32, meter: &mut WeightMeter) {
+		let mut slot = OldestRetainedSlot::<T>::get();
+		while slot < cutoff
+			&& *budget > 0
+			&& meter.try_consume(T::WeightInfo::prune_header()).is_ok()
+		{
+			let Some(next) = NextHeaderSlot::<T>::take(slot) else {
+				break;
+			};
+			Self::remove_slot(slot);
+			slot = next;
+			*budget -= 1;
+		}
+		OldestRetainedSlot::<T>::set(slot);
+	}
+
+	/// Removes the sync committees of the periods entirely before `cutoff`.
+	pub(crate) fn prune_sync_committees(cutoff: u64, budget: &mut u32, meter: &mut WeightMeter) {
+		let Some(cutoff_period) =
+			cutoff.checked_div(ConfigurationStorage::<T>::get().slots_per_period)
+		else {
+			return;
+		};
+
+		let mut period = OldestRetainedPeriod::<T>::get();
+		while period < cutoff_period
+			&& *budget > 0
+			&& meter
+				.try_consume(T::WeightInfo::prune_sync_committee())
+				.is_ok()
+		{
+			SyncCommitteePoseidons::<T>::remove(period);
+			SyncCommitteeHashes::<T>::remove(period);
+			period = period.saturating_add(1);
+			*budget -= 1;
+		}
+		OldestRetainedPeriod::<T>::set(period);
+	}
+
+	/// Continues the sweep of legacy headers, if any, once they are all older than `cutoff`.
+	pub(crate) fn prune_legacy_headers(cutoff: u64, budget: &mut u32, meter: &mut WeightMeter) {
+		let Some(mut legacy) = LegacyHeadersPruning::<T>::get() else {
+			return;
+		};
+		if legacy.end > cutoff {
+			return;
+		}
+
+		let mut slots = match &legacy.cursor {
+			Some(cursor) => Headers::<T>::iter_keys_from(cursor.to_vec()),
+			None => Headers::<T>::iter_keys(),
+		};
+
+		loop {
+			if *budget == 0
+				|| meter
+					.try_consume(T::WeightInfo::prune_legacy_header())
+					.is_err()
+			{
+				legacy.cursor = Some(BoundedVec::defensive_truncate_from(
+					slots.last_raw_key().to_vec(),
+				));
+				break;
+			}
+			*budget -= 1;
+
+			match slots.next() {
+				Some(slot) if slot < legacy.end => Self::remove_slot(slot),
+				Some(_) => {},
+				None => {
+					LegacyHeadersPruning::<T>::kill();
+					return;
+				},
+			}
+		}
+		LegacyHeadersPruning::<T>::set(Some(legacy));
+	}
+
+	fn remove_slot(slot: u64) {
+		Headers::<T>::remove(slot);
+		ExecutionStateRoots::<T>::remove(slot);
+		Timestamps::<T>::remove(slot);
+	}
+}
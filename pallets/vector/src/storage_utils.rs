@@ -12,6 +12,8 @@ pub enum MessageStatusEnum {
 	#[default]
 	NotExecuted,
 	ExecutionSucceeded,
+	/// The message was proven, but its handler failed. It cannot be executed again.
+	ExecutionFailed,
 }
 
 #[derive(Debug, PartialEq)]
@@ -1,21 +1,29 @@
 use crate::{
+	migrations::v1::MigrateToV1,
 	mock::{
-		new_test_ext, Balances, Bridge, RuntimeEvent, RuntimeOrigin, System, Test,
-		ROTATE_FUNCTION_ID, ROTATE_VK, STEP_FUNCTION_ID, STEP_VK,
+		new_test_ext, Assets, Balances, Bridge, FailHandler, HandledMessages, RuntimeEvent,
+		RuntimeOrigin, System, Test, ROTATE_FUNCTION_ID, ROTATE_VK, STEP_FUNCTION_ID, STEP_VK,
 	},
 	state::Configuration,
 	storage_utils::MessageStatusEnum,
-	Broadcasters, ConfigurationStorage, Error, Event, ExecutionStateRoots, FunctionIds,
-	FunctionInput, FunctionOutput, FunctionProof, Head, Headers, MessageStatus, ProofOutputs,
-	RotateVerificationKey, SP1VerificationKey, SourceChainFrozen, StepVerificationKey,
-	SyncCommitteeHashes, SyncCommitteePoseidons, Updater, ValidProof, WhitelistedDomains,
+	BridgeMode, BridgedAsset, BridgedAssets, Broadcasters, ConfigurationStorage,
+	DestinationChainFrozen, Error, Event, ExecutionStateRoots, FlowDirection, FlowLimit,
+	FlowLimits, FlowUsage, FlowUsages, FunctionIds, FunctionInput, FunctionOutput, FunctionProof,
+	Head, Headers, LegacyHeadersPruning, LocalAsset, MessageStatus, NextHeaderSlot,
+	OldestRetainedPeriod, OldestRetainedSlot, ProofOutputs, RotateVerificationKey,
+	SP1VerificationKey, SourceChainFrozen, StepVerificationKey, SyncCommitteeHashes,
+	SyncCommitteePoseidons, Timestamps, Updater, ValidProof, WhitelistedDomains,
 };
 use alloy_sol_types::SolValue;
 use avail_core::data_proof::Message::FungibleToken;
 use avail_core::data_proof::{tx_uid, AddressedMessage, Message};
 use frame_support::{
 	assert_err, assert_ok,
-	traits::{fungible::Inspect, DefensiveTruncateFrom},
+	traits::{
+		fungible::Inspect, fungibles, DefensiveTruncateFrom, GetStorageVersion, Hooks,
+		OnRuntimeUpgrade, StorageVersion,
+	},
+	weights::Weight,
 	BoundedVec,
 };
 use frame_system::RawOrigin;
@@ -23,7 +31,7 @@ use hex_literal::hex;
 use primitive_types::U256;
 use sp1_sdk::SP1ProofWithPublicValues;
 use sp_core::{crypto::AccountId32, keccak_256, ByteArray};
-use sp_runtime::{testing::H256, traits::BadOrigin};
+use sp_runtime::{testing::H256, traits::BadOrigin, DispatchError};
 
 const TEST_SENDER_VEC: [u8; 32] =
 	hex!("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
@@ -1071,6 +1079,63 @@ fn source_chain_froze_does_not_work_with_non_root() {
 	});
 }
 
+#[test]
+fn destination_chain_froze_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let destination_chain_id = 2;
+		let frozen = true;
+		assert_ne!(
+			DestinationChainFrozen::<Test>::get(destination_chain_id),
+			frozen
+		);
+
+		let ok =
+			Bridge::destination_chain_froze(RawOrigin::Root.into(), destination_chain_id, frozen);
+		assert_ok!(ok);
+		assert_eq!(
+			DestinationChainFrozen::<Test>::get(destination_chain_id),
+			frozen
+		);
+
+		let expected_event = RuntimeEvent::Bridge(Event::DestinationChainFrozen {
+			destination_chain_id,
+			frozen,
+		});
+		System::assert_last_event(expected_event);
+	});
+}
+
+#[test]
+fn destination_chain_froze_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+
+		let ok = Bridge::destination_chain_froze(origin, 2, true);
+		assert_err!(ok, BadOrigin);
+	});
+}
+
+#[test]
+fn send_message_does_not_work_with_frozen_destination_chain() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let message = Message::ArbitraryMessage(BoundedVec::truncate_from([0, 1, 2, 3].to_vec()));
+
+		// Freezing the source chain only stops the execution of its messages.
+		SourceChainFrozen::<Test>::set(2, true);
+		assert_ok!(Bridge::send_message(
+			origin.clone(),
+			message.clone(),
+			ROTATE_FUNCTION_ID,
+			2
+		));
+
+		DestinationChainFrozen::<Test>::set(2, true);
+		let err = Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2);
+		assert_err!(err, Error::<Test>::DestinationChainFrozen);
+	});
+}
+
 #[test]
 fn send_message_arbitrary_message_works() {
 	new_test_ext().execute_with(|| {
@@ -1207,6 +1272,86 @@ fn execute_arbitrary_message_works() {
 	});
 }
 
+fn set_amb_execution_state() -> u64 {
+	Broadcasters::<Test>::set(
+		2,
+		H256(hex!(
+			"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
+		)),
+	);
+
+	let slot = 5085118;
+	ExecutionStateRoots::<Test>::set(
+		slot,
+		H256(hex!(
+			"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+		)),
+	);
+	slot
+}
+
+#[test]
+fn execute_arbitrary_message_is_handled() {
+	new_test_ext().execute_with(|| {
+		let slot = set_amb_execution_state();
+		let message = get_valid_amb_message();
+
+		assert_ok!(Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message.clone(),
+			get_valid_amb_account_proof(),
+			get_valid_amb_storage_proof(),
+		));
+
+		assert_eq!(
+			HandledMessages::get(),
+			vec![(2, message.from, message.to, b"Hello, World!".to_vec())]
+		);
+	});
+}
+
+#[test]
+fn execute_arbitrary_message_records_handler_failure() {
+	new_test_ext().execute_with(|| {
+		FailHandler::set(&true);
+		let slot = set_amb_execution_state();
+		let message = get_valid_amb_message();
+		let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+
+		assert_ok!(Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message.clone(),
+			get_valid_amb_account_proof(),
+			get_valid_amb_storage_proof(),
+		));
+
+		// Changes made by the handler are reverted.
+		assert!(HandledMessages::get().is_empty());
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionFailed
+		);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::MessageExecutionFailed {
+			from: message.from,
+			to: message.to,
+			message_id: message.id,
+			message_root,
+			error: DispatchError::Other("Handler failed"),
+		}));
+
+		let err = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message,
+			get_valid_amb_account_proof(),
+			get_valid_amb_storage_proof(),
+		);
+		assert_err!(err, Error::<Test>::MessageAlreadyExecuted);
+	});
+}
+
 #[test]
 fn test_double_execute_arbitrary_message() {
 	new_test_ext().execute_with(|| {
@@ -1741,3 +1886,684 @@ fn set_sync_committee_hash_non_root() {
 		assert_eq!(SyncCommitteeHashes::<Test>::get(period), H256::zero());
 	});
 }
+
+const WRAPPED_ASSET_ID: H256 = H256([1u8; 32]);
+const LOCAL_ASSET_ID: u32 = 1;
+
+fn register_wrapped_asset(mode: BridgeMode) {
+	let sender: AccountId32 = TEST_SENDER_VEC.into();
+	assert_ok!(Assets::force_create(
+		RawOrigin::Root.into(),
+		LOCAL_ASSET_ID,
+		sender.clone(),
+		true,
+		1
+	));
+	assert_ok!(Assets::mint(
+		RuntimeOrigin::signed(sender),
+		LOCAL_ASSET_ID,
+		TEST_SENDER_ACCOUNT,
+		1_000
+	));
+
+	let asset = BridgedAsset {
+		local: LocalAsset::Fungible(LOCAL_ASSET_ID),
+		mode,
+	};
+	assert_ok!(Bridge::set_bridged_asset(
+		RawOrigin::Root.into(),
+		WRAPPED_ASSET_ID,
+		Some(asset)
+	));
+}
+
+fn wrapped_balance(who: &AccountId32) -> u128 {
+	<Assets as fungibles::Inspect<_>>::balance(LOCAL_ASSET_ID, who)
+}
+
+#[test]
+fn set_bridged_asset_works_with_root() {
+	new_test_ext().execute_with(|| {
+		register_wrapped_asset(BridgeMode::MintBurn);
+
+		let asset = BridgedAsset {
+			local: LocalAsset::Fungible(LOCAL_ASSET_ID),
+			mode: BridgeMode::MintBurn,
+		};
+		assert_eq!(BridgedAssets::<Test>::get(WRAPPED_ASSET_ID), Some(asset));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::BridgedAssetSet {
+			asset_id: WRAPPED_ASSET_ID,
+			asset: Some(asset),
+		}));
+
+		let ok = Bridge::set_bridged_asset(RawOrigin::Root.into(), WRAPPED_ASSET_ID, None);
+		assert_ok!(ok);
+		assert_eq!(Bridge::bridged_asset(WRAPPED_ASSET_ID), None);
+	});
+}
+
+#[test]
+fn set_bridged_asset_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+
+		let err = Bridge::set_bridged_asset(origin, WRAPPED_ASSET_ID, Some(Default::default()));
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn set_bridged_asset_does_not_accept_unknown_asset() {
+	new_test_ext().execute_with(|| {
+		let asset = BridgedAsset {
+			local: LocalAsset::Fungible(LOCAL_ASSET_ID),
+			mode: BridgeMode::MintBurn,
+		};
+
+		let err = Bridge::set_bridged_asset(RawOrigin::Root.into(), WRAPPED_ASSET_ID, Some(asset));
+		assert_err!(err, Error::<Test>::UnknownLocalAsset);
+	});
+}
+
+#[test]
+fn set_bridged_asset_does_not_accept_native_mint_burn() {
+	new_test_ext().execute_with(|| {
+		let asset = BridgedAsset {
+			local: LocalAsset::Native,
+			mode: BridgeMode::MintBurn,
+		};
+
+		let err = Bridge::set_bridged_asset(RawOrigin::Root.into(), WRAPPED_ASSET_ID, Some(asset));
+		assert_err!(err, Error::<Test>::UnsupportedBridgeMode);
+	});
+}
+
+#[test]
+fn avail_is_bridged_by_default() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			Bridge::bridged_asset(H256::zero()),
+			Some(BridgedAsset {
+				local: LocalAsset::Native,
+				mode: BridgeMode::LockUnlock,
+			})
+		);
+		assert_eq!(Bridge::bridged_asset(WRAPPED_ASSET_ID), None);
+	});
+}
+
+#[test]
+fn send_message_fungible_token_does_not_accept_unknown_asset() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let message = Message::FungibleToken {
+			asset_id: WRAPPED_ASSET_ID,
+			amount: 100,
+		};
+
+		let err = Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2);
+		assert_err!(err, Error::<Test>::AssetNotSupported);
+	});
+}
+
+#[test]
+fn send_message_wrapped_token_burns() {
+	new_test_ext().execute_with(|| {
+		register_wrapped_asset(BridgeMode::MintBurn);
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let message = Message::FungibleToken {
+			asset_id: WRAPPED_ASSET_ID,
+			amount: 100,
+		};
+
+		let ok = Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2);
+		assert_ok!(ok);
+		assert_eq!(wrapped_balance(&TEST_SENDER_ACCOUNT), 900);
+		assert_eq!(wrapped_balance(&Bridge::account_id()), 0);
+		System::assert_has_event(RuntimeEvent::Bridge(Event::BridgedAssetSent {
+			asset_id: WRAPPED_ASSET_ID,
+			from: TEST_SENDER_ACCOUNT,
+			amount: 100,
+			mode: BridgeMode::MintBurn,
+		}));
+	});
+}
+
+#[test]
+fn send_message_wrapped_token_locks() {
+	new_test_ext().execute_with(|| {
+		register_wrapped_asset(BridgeMode::LockUnlock);
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let message = Message::FungibleToken {
+			asset_id: WRAPPED_ASSET_ID,
+			amount: 100,
+		};
+
+		let ok = Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2);
+		assert_ok!(ok);
+		assert_eq!(wrapped_balance(&TEST_SENDER_ACCOUNT), 900);
+		assert_eq!(wrapped_balance(&Bridge::account_id()), 100);
+	});
+}
+
+#[test]
+fn receive_wrapped_token_mints() {
+	new_test_ext().execute_with(|| {
+		register_wrapped_asset(BridgeMode::MintBurn);
+		let to = AccountId32::new([3u8; 32]);
+
+		assert_ok!(Bridge::receive_asset(WRAPPED_ASSET_ID, &to, 100));
+		assert_eq!(wrapped_balance(&to), 100);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::BridgedAssetReceived {
+			asset_id: WRAPPED_ASSET_ID,
+			to,
+			amount: 100,
+			mode: BridgeMode::MintBurn,
+		}));
+	});
+}
+
+#[test]
+fn receive_wrapped_token_unlocks() {
+	new_test_ext().execute_with(|| {
+		register_wrapped_asset(BridgeMode::LockUnlock);
+		let to = AccountId32::new([3u8; 32]);
+
+		// Nothing has been locked yet.
+		let err = Bridge::receive_asset(WRAPPED_ASSET_ID, &to, 100);
+		assert!(err.is_err());
+
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let message = Message::FungibleToken {
+			asset_id: WRAPPED_ASSET_ID,
+			amount: 100,
+		};
+		assert_ok!(Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2));
+
+		assert_ok!(Bridge::receive_asset(WRAPPED_ASSET_ID, &to, 100));
+		assert_eq!(wrapped_balance(&to), 100);
+		assert_eq!(wrapped_balance(&Bridge::account_id()), 0);
+	});
+}
+
+fn flow_limit(max: u128) -> FlowLimit {
+	FlowLimit {
+		window: 10,
+		max_inflow: max,
+		max_outflow: max,
+	}
+}
+
+#[test]
+fn set_flow_limit_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let limit = flow_limit(100);
+
+		assert_ok!(Bridge::set_flow_limit(
+			RawOrigin::Root.into(),
+			2,
+			WRAPPED_ASSET_ID,
+			Some(limit)
+		));
+		assert_eq!(FlowLimits::<Test>::get(2, WRAPPED_ASSET_ID), Some(limit));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::FlowLimitSet {
+			domain: 2,
+			asset_id: WRAPPED_ASSET_ID,
+			limit: Some(limit),
+		}));
+
+		assert_ok!(Bridge::set_flow_limit(
+			RawOrigin::Root.into(),
+			2,
+			WRAPPED_ASSET_ID,
+			None
+		));
+		assert_eq!(FlowLimits::<Test>::get(2, WRAPPED_ASSET_ID), None);
+	});
+}
+
+#[test]
+fn set_flow_limit_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::set_flow_limit(origin, 2, WRAPPED_ASSET_ID, Some(flow_limit(100)));
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn set_flow_limit_does_not_accept_empty_window() {
+	new_test_ext().execute_with(|| {
+		let limit = FlowLimit {
+			window: 0,
+			..flow_limit(100)
+		};
+		let err = Bridge::set_flow_limit(RawOrigin::Root.into(), 2, WRAPPED_ASSET_ID, Some(limit));
+		assert_err!(err, Error::<Test>::InvalidFlowLimit);
+	});
+}
+
+#[test]
+fn send_message_over_flow_limit_freezes_domain() {
+	new_test_ext().execute_with(|| {
+		register_wrapped_asset(BridgeMode::LockUnlock);
+		assert_ok!(Bridge::set_flow_limit(
+			RawOrigin::Root.into(),
+			2,
+			WRAPPED_ASSET_ID,
+			Some(flow_limit(150))
+		));
+		let message = Message::FungibleToken {
+			asset_id: WRAPPED_ASSET_ID,
+			amount: 100,
+		};
+
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		assert_ok!(Bridge::send_message(
+			origin.clone(),
+			message.clone(),
+			ROTATE_FUNCTION_ID,
+			2
+		));
+		assert_eq!(wrapped_balance(&Bridge::account_id()), 100);
+
+		// Crossing the limit does not move funds, and freezes the domain only for sending.
+		assert_ok!(Bridge::send_message(
+			origin.clone(),
+			message.clone(),
+			ROTATE_FUNCTION_ID,
+			2
+		));
+		assert_eq!(wrapped_balance(&Bridge::account_id()), 100);
+		assert!(DestinationChainFrozen::<Test>::get(2));
+		assert!(!SourceChainFrozen::<Test>::get(2));
+		System::assert_has_event(RuntimeEvent::Bridge(Event::DestinationChainFrozen {
+			destination_chain_id: 2,
+			frozen: true,
+		}));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::FlowLimitExceeded {
+			domain: 2,
+			asset_id: WRAPPED_ASSET_ID,
+			direction: FlowDirection::Outflow,
+			amount: 100,
+		}));
+
+		let err = Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2);
+		assert_err!(err, Error::<Test>::DestinationChainFrozen);
+	});
+}
+
+#[test]
+fn send_message_without_funds_does_not_freeze_domain() {
+	new_test_ext().execute_with(|| {
+		register_wrapped_asset(BridgeMode::LockUnlock);
+		assert_ok!(Bridge::set_flow_limit(
+			RawOrigin::Root.into(),
+			2,
+			WRAPPED_ASSET_ID,
+			Some(flow_limit(150))
+		));
+		let message = Message::FungibleToken {
+			asset_id: WRAPPED_ASSET_ID,
+			amount: u128::MAX,
+		};
+
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2);
+		assert!(err.is_err());
+		assert!(!DestinationChainFrozen::<Test>::get(2));
+		assert_eq!(
+			FlowUsages::<Test>::get(2, WRAPPED_ASSET_ID),
+			FlowUsage::default()
+		);
+	});
+}
+
+#[test]
+fn flow_limit_rolls_over_windows() {
+	new_test_ext().execute_with(|| {
+		FlowLimits::<Test>::insert(2, WRAPPED_ASSET_ID, flow_limit(100));
+		let outflow =
+			|amount| Bridge::note_flow(2, WRAPPED_ASSET_ID, amount, FlowDirection::Outflow);
+
+		System::set_block_number(1);
+		assert!(outflow(100));
+
+		// Half of the previous window still counts.
+		System::set_block_number(15);
+		assert!(outflow(50));
+
+		// Inflows are limited separately.
+		assert!(Bridge::note_flow(
+			2,
+			WRAPPED_ASSET_ID,
+			100,
+			FlowDirection::Inflow
+		));
+
+		assert!(!outflow(1));
+	});
+}
+
+#[test]
+fn execute_over_flow_limit_freezes_domain() {
+	new_test_ext().execute_with(|| {
+		let balance_before = Balances::balance(&Bridge::account_id());
+		let slot = set_fungible_execution_state();
+		FlowLimits::<Test>::insert(2, H256::zero(), flow_limit(1_000));
+
+		let message = get_valid_message();
+		let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+
+		// amount in message 1000000000000000000
+		assert_ok!(Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message.clone(),
+			get_valid_account_proof(),
+			get_valid_storage_proof(),
+		));
+		assert_eq!(Balances::balance(&Bridge::account_id()), balance_before);
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::NotExecuted
+		);
+		assert!(SourceChainFrozen::<Test>::get(2));
+		assert!(!DestinationChainFrozen::<Test>::get(2));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::FlowLimitExceeded {
+			domain: 2,
+			asset_id: H256::zero(),
+			direction: FlowDirection::Inflow,
+			amount: 1_000_000_000_000_000_000,
+		}));
+
+		let err = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message,
+			get_valid_account_proof(),
+			get_valid_storage_proof(),
+		);
+		assert_err!(err, Error::<Test>::SourceChainFrozen);
+	});
+}
+
+#[test]
+fn execute_batch_freezes_domain_over_flow_limit() {
+	new_test_ext().execute_with(|| {
+		let slot = set_fungible_execution_state();
+		FlowLimits::<Test>::insert(2, H256::zero(), flow_limit(1_000));
+
+		let message = get_valid_message();
+		let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+		let messages = BoundedVec::truncate_from(vec![
+			(message.clone(), get_valid_storage_proof()),
+			(message.clone(), get_valid_storage_proof()),
+		]);
+
+		assert_ok!(Bridge::execute_batch(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			2,
+			get_valid_account_proof(),
+			messages,
+		));
+		assert!(SourceChainFrozen::<Test>::get(2));
+		System::assert_has_event(RuntimeEvent::Bridge(Event::FlowLimitExceeded {
+			domain: 2,
+			asset_id: H256::zero(),
+			direction: FlowDirection::Inflow,
+			amount: 1_000_000_000_000_000_000,
+		}));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::BatchMessageFailed {
+			message_id: message.id,
+			message_root,
+			error: Error::<Test>::SourceChainFrozen.into(),
+		}));
+	});
+}
+
+fn set_fungible_execution_state() -> u64 {
+	Broadcasters::<Test>::set(
+		2,
+		H256(hex!(
+			"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+		)),
+	);
+
+	let slot = 8581263;
+	ExecutionStateRoots::<Test>::set(
+		slot,
+		H256(hex!(
+			"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+		)),
+	);
+	slot
+}
+
+#[test]
+fn execute_batch_works() {
+	new_test_ext().execute_with(|| {
+		let balance_before = Balances::balance(&Bridge::account_id());
+		let slot = set_fungible_execution_state();
+		let message = get_valid_message();
+		let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+		let messages =
+			BoundedVec::truncate_from(vec![(message.clone(), get_valid_storage_proof())]);
+
+		assert_ok!(Bridge::execute_batch(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			2,
+			get_valid_account_proof(),
+			messages,
+		));
+
+		// amount in message 1000000000000000000
+		assert_eq!(
+			Balances::balance(&Bridge::account_id()),
+			balance_before - 1_000_000_000_000_000_000
+		);
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionSucceeded
+		);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::MessageExecuted {
+			from: message.from,
+			to: message.to,
+			message_id: message.id,
+			message_root,
+		}));
+	});
+}
+
+#[test]
+fn execute_batch_reports_failed_messages() {
+	new_test_ext().execute_with(|| {
+		let slot = set_fungible_execution_state();
+		let message = get_valid_message();
+		let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+		let other_domain_message = AddressedMessage {
+			origin_domain: 3,
+			..message.clone()
+		};
+		let other_domain_root = H256(keccak_256(
+			other_domain_message.clone().abi_encode().as_slice(),
+		));
+		let messages = BoundedVec::truncate_from(vec![
+			(other_domain_message, get_valid_storage_proof()),
+			(message.clone(), get_valid_storage_proof()),
+			(message.clone(), get_valid_storage_proof()),
+		]);
+
+		let post_info = Bridge::execute_batch(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			2,
+			get_valid_account_proof(),
+			messages,
+		)
+		.unwrap();
+
+		// Only the executed message is charged on top of the batch.
+		let weight = <() as crate::WeightInfo>::execute_batch(3, 0)
+			.saturating_add(Bridge::batch_message_weight(&message.message));
+		assert_eq!(post_info.actual_weight, Some(weight));
+
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionSucceeded
+		);
+		System::assert_has_event(RuntimeEvent::Bridge(Event::BatchMessageFailed {
+			message_id: message.id,
+			message_root: other_domain_root,
+			error: Error::<Test>::OriginDomainMismatch.into(),
+		}));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::BatchMessageFailed {
+			message_id: message.id,
+			message_root,
+			error: Error::<Test>::MessageAlreadyExecuted.into(),
+		}));
+	});
+}
+
+#[test]
+fn execute_batch_does_not_accept_faulty_account_proof() {
+	new_test_ext().execute_with(|| {
+		let slot = set_fungible_execution_state();
+		let messages =
+			BoundedVec::truncate_from(vec![(get_valid_message(), get_valid_storage_proof())]);
+
+		let err = Bridge::execute_batch(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			2,
+			get_invalid_account_proof(),
+			messages,
+		);
+		assert_err!(err, Error::<Test>::CannotGetStorageRoot);
+	});
+}
+
+#[test]
+fn execute_batch_does_not_accept_empty_batch() {
+	new_test_ext().execute_with(|| {
+		let slot = set_fungible_execution_state();
+
+		let err = Bridge::execute_batch(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			2,
+			get_valid_account_proof(),
+			BoundedVec::new(),
+		);
+		assert_err!(err, Error::<Test>::InvalidBridgeInputs);
+	});
+}
+
+/// Stores the roots of `slot` as the new head, like a step does.
+fn store_header(slot: u64) {
+	NextHeaderSlot::<Test>::insert(Head::<Test>::get(), slot);
+	Head::<Test>::set(slot);
+	Headers::<Test>::insert(slot, H256::repeat_byte(1));
+	ExecutionStateRoots::<Test>::insert(slot, H256::repeat_byte(2));
+	Timestamps::<Test>::insert(slot, slot);
+}
+
+fn stored_slots() -> Vec<u64> {
+	let mut slots: Vec<u64> = Headers::<Test>::iter_keys().collect();
+	slots.sort();
+	slots
+}
+
+#[test]
+fn on_idle_prunes_headers_outside_retention_window() {
+	new_test_ext().execute_with(|| {
+		for slot in [100, 200, 300, 400, 500] {
+			store_header(slot);
+		}
+
+		// At most 3 slots are pruned per block, starting from the empty slot 0.
+		Bridge::on_idle(1, Weight::MAX);
+		assert_eq!(OldestRetainedSlot::<Test>::get(), 300);
+		assert_eq!(stored_slots(), vec![300, 400, 500]);
+
+		// Slots at least 100 slots behind the head are kept.
+		Bridge::on_idle(2, Weight::MAX);
+		assert_eq!(OldestRetainedSlot::<Test>::get(), 400);
+		assert_eq!(stored_slots(), vec![400, 500]);
+		assert_eq!(ExecutionStateRoots::<Test>::get(300), H256::zero());
+		assert_eq!(Timestamps::<Test>::get(300), 0);
+		assert_eq!(ExecutionStateRoots::<Test>::get(400), H256::repeat_byte(2));
+	});
+}
+
+#[test]
+fn on_idle_prunes_sync_committees_outside_retention_window() {
+	new_test_ext().execute_with(|| {
+		ConfigurationStorage::<Test>::set(Configuration {
+			slots_per_period: 100,
+			finality_threshold: 461,
+		});
+		for period in 0..4 {
+			SyncCommitteePoseidons::<Test>::insert(period, U256::one());
+			SyncCommitteeHashes::<Test>::insert(period, H256::repeat_byte(1));
+		}
+		Head::<Test>::set(350);
+
+		Bridge::on_idle(1, Weight::MAX);
+
+		assert_eq!(OldestRetainedPeriod::<Test>::get(), 2);
+		assert_eq!(SyncCommitteePoseidons::<Test>::get(1), U256::zero());
+		assert_eq!(SyncCommitteeHashes::<Test>::get(1), H256::zero());
+		assert_eq!(SyncCommitteePoseidons::<Test>::get(2), U256::one());
+		assert_eq!(SyncCommitteeHashes::<Test>::get(3), H256::repeat_byte(1));
+	});
+}
+
+#[test]
+fn on_idle_does_not_prune_without_weight() {
+	new_test_ext().execute_with(|| {
+		for slot in [100, 200, 300] {
+			store_header(slot);
+		}
+
+		assert_eq!(Bridge::on_idle(1, Weight::zero()), Weight::zero());
+		assert_eq!(stored_slots(), vec![100, 200, 300]);
+	});
+}
+
+#[test]
+fn migration_prunes_legacy_headers() {
+	new_test_ext().execute_with(|| {
+		for slot in [10, 20, 150, 300] {
+			Headers::<Test>::insert(slot, H256::repeat_byte(1));
+			ExecutionStateRoots::<Test>::insert(slot, H256::repeat_byte(2));
+		}
+		Head::<Test>::set(300);
+		StorageVersion::new(0).put::<Bridge>();
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(OldestRetainedSlot::<Test>::get(), 300);
+		assert_eq!(Bridge::on_chain_storage_version(), 1);
+
+		// The sweep waits until every legacy slot is outside the retention window.
+		store_header(350);
+		for n in 1..5 {
+			Bridge::on_idle(n, Weight::MAX);
+		}
+		assert_eq!(stored_slots(), vec![10, 20, 150, 300, 350]);
+		assert!(LegacyHeadersPruning::<Test>::get().is_some());
+
+		// Then a single pass sweeps them in chunks.
+		store_header(400);
+		store_header(500);
+		Bridge::on_idle(5, Weight::MAX);
+		assert!(LegacyHeadersPruning::<Test>::get().is_some());
+		for n in 6..10 {
+			Bridge::on_idle(n, Weight::MAX);
+		}
+		assert_eq!(stored_slots(), vec![400, 500]);
+		assert_eq!(LegacyHeadersPruning::<Test>::get(), None);
+	});
+}
@@ -15,15 +15,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Autogenerated weights for `pallet_vector`
+//! Weights for `pallet_vector`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-03-26, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `ip-172-31-12-189`, CPU: `Intel(R) Xeon(R) Platinum 8175M CPU @ 2.50GHz`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//! Generated with the Substrate benchmark CLI version 32.0.0 on 2024-03-26, then edited by
+//! hand: the entries marked as estimated were derived from their storage accesses and were
+//! not benchmarked. Regenerate this file with the command below before relying on them.
 
-// Executed Command:
+// Command:
 // ./target/release/avail-node
 // benchmark
 // pallet
@@ -57,6 +55,7 @@ pub trait WeightInfo {
 	fn set_whitelisted_domains() -> Weight;
 	fn set_configuration() -> Weight;
 	fn source_chain_froze() -> Weight;
+	fn destination_chain_froze() -> Weight;
 	fn fulfill_call_step() -> Weight;
 	fn fulfill_call_rotate() -> Weight;
 	fn execute_fungible_token() -> Weight;
@@ -69,6 +68,12 @@ pub trait WeightInfo {
 	fn set_sp1_verification_key() -> Weight;
 	fn set_sync_committee_hash() -> Weight;
 	fn fulfill() -> Weight;
+	fn set_bridged_asset() -> Weight;
+	fn set_flow_limit() -> Weight;
+	fn prune_header() -> Weight;
+	fn prune_sync_committee() -> Weight;
+	fn prune_legacy_header() -> Weight;
+	fn execute_batch(n: u32, l: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_vector` using the Avail node and recommended hardware.
@@ -76,27 +81,41 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Vector::WhitelistedDomains` (r:1 w:0)
 	/// Proof: `Vector::WhitelistedDomains` (`max_values`: Some(1), `max_size`: Some(40002), added: 40497, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::DestinationChainFrozen` (r:1 w:0)
+	/// Proof: `Vector::DestinationChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 102400]`.
 	fn send_message_arbitrary_message(_l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `357`
 		//  Estimated: `41487`
-		// Minimum execution time: 19_199_000 picoseconds.
+		// Estimated from the storage accesses, not benchmarked.
 		Weight::from_parts(20_257_871, 41487)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 	}
 	/// Storage: `Vector::WhitelistedDomains` (r:1 w:0)
 	/// Proof: `Vector::WhitelistedDomains` (`max_values`: Some(1), `max_size`: Some(40002), added: 40497, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::DestinationChainFrozen` (r:1 w:1)
+	/// Proof: `Vector::DestinationChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::FlowLimits` (r:1 w:0)
+	/// Proof: `Vector::FlowLimits` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::FlowUsages` (r:1 w:1)
+	/// Proof: `Vector::FlowUsages` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::BridgedAssets` (r:1 w:0)
+	/// Proof: `Vector::BridgedAssets` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	fn send_message_fungible_token() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `586`
 		//  Estimated: `41487`
-		// Minimum execution time: 87_666_000 picoseconds.
-		Weight::from_parts(88_852_000, 41487)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(131_052_000, 41487)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Vector::SyncCommitteePoseidons` (r:0 w:1)
 	/// Proof: `Vector::SyncCommitteePoseidons` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
@@ -149,6 +168,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(12_985_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Vector::DestinationChainFrozen` (r:0 w:1)
+	/// Proof: `Vector::DestinationChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	fn destination_chain_froze() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(12_985_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: `Vector::ConfigurationStorage` (r:1 w:0)
 	/// Proof: `Vector::ConfigurationStorage` (`max_values`: Some(1), `max_size`: Some(10), added: 505, mode: `MaxEncodedLen`)
 	/// Storage: `Vector::FunctionIds` (r:1 w:0)
@@ -167,14 +196,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Vector::Timestamps` (r:0 w:1)
 	/// Proof: `Vector::Timestamps` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::NextHeaderSlot` (r:0 w:1)
+	/// Proof: `Vector::NextHeaderSlot` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
 	fn fulfill_call_step() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2599`
 		//  Estimated: `11488`
-		// Minimum execution time: 25_210_559_000 picoseconds.
+		// Estimated from the storage accesses, not benchmarked.
 		Weight::from_parts(25_282_049_000, 11488)
 			.saturating_add(T::DbWeight::get().reads(8_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Vector::ConfigurationStorage` (r:1 w:0)
 	/// Proof: `Vector::ConfigurationStorage` (`max_values`: Some(1), `max_size`: Some(10), added: 505, mode: `MaxEncodedLen`)
@@ -201,20 +232,30 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Vector::WhitelistedDomains` (`max_values`: Some(1), `max_size`: Some(40002), added: 40497, mode: `MaxEncodedLen`)
 	/// Storage: `Vector::Broadcasters` (r:1 w:0)
 	/// Proof: `Vector::Broadcasters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
-	/// Storage: `Vector::SourceChainFrozen` (r:1 w:0)
+	/// Storage: `Vector::SourceChainFrozen` (r:1 w:1)
 	/// Proof: `Vector::SourceChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
 	/// Storage: `Vector::ExecutionStateRoots` (r:1 w:0)
 	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::FlowLimits` (r:1 w:0)
+	/// Proof: `Vector::FlowLimits` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::FlowUsages` (r:1 w:1)
+	/// Proof: `Vector::FlowUsages` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::BridgedAssets` (r:1 w:0)
+	/// Proof: `Vector::BridgedAssets` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	fn execute_fungible_token() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `672`
 		//  Estimated: `41487`
-		// Minimum execution time: 202_005_000 picoseconds.
-		Weight::from_parts(204_185_000, 41487)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(251_885_000, 41487)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Vector::MessageStatus` (r:1 w:1)
 	/// Proof: `Vector::MessageStatus` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
@@ -318,14 +359,110 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Vector::Timestamps` (r:0 w:1)
 	/// Proof: `Vector::Timestamps` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::NextHeaderSlot` (r:0 w:1)
+	/// Proof: `Vector::NextHeaderSlot` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
 	fn fulfill() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `614`
 		//  Estimated: `6020`
-		// Minimum execution time: 532_131_000_000 picoseconds.
+		// Estimated from the storage accesses, not benchmarked.
 		Weight::from_parts(537_965_000_000, 6020)
 			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::BridgedAssets` (r:0 w:1)
+	/// Proof: `Vector::BridgedAssets` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	fn set_bridged_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `3675`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(10_000_000, 3675)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::FlowUsages` (r:0 w:1)
+	/// Proof: `Vector::FlowUsages` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::FlowLimits` (r:0 w:1)
+	/// Proof: `Vector::FlowLimits` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn set_flow_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(11_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Vector::NextHeaderSlot` (r:1 w:1)
+	/// Proof: `Vector::NextHeaderSlot` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Headers` (r:0 w:1)
+	/// Proof: `Vector::Headers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ExecutionStateRoots` (r:0 w:1)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Timestamps` (r:0 w:1)
+	/// Proof: `Vector::Timestamps` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn prune_header() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `3481`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(13_000_000, 3481)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Vector::SyncCommitteePoseidons` (r:0 w:1)
+	/// Proof: `Vector::SyncCommitteePoseidons` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SyncCommitteeHashes` (r:0 w:1)
+	/// Proof: `Vector::SyncCommitteeHashes` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn prune_sync_committee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(7_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Vector::Headers` (r:1 w:1)
+	/// Proof: `Vector::Headers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ExecutionStateRoots` (r:0 w:1)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Timestamps` (r:0 w:1)
+	/// Proof: `Vector::Timestamps` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn prune_legacy_header() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `171`
+		//  Estimated: `3505`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(15_000_000, 3505)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Vector::ExecutionStateRoots` (r:1 w:0)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Broadcasters` (r:1 w:0)
+	/// Proof: `Vector::Broadcasters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::WhitelistedDomains` (r:1 w:0)
+	/// Proof: `Vector::WhitelistedDomains` (`max_values`: Some(1), `max_size`: Some(40002), added: 40497, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceChainFrozen` (r:1 w:0)
+	/// Proof: `Vector::SourceChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::MessageStatus` (r:64 w:64)
+	/// Proof: `Vector::MessageStatus` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 64]`.
+	/// The range of component `l` is `[0, 102400]`.
+	fn execute_batch(n: u32, l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `494 + n * (32 ±0)`
+		//  Estimated: `41487 + n * (2508 ±0)`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(68_312_000, 41487)
+			.saturating_add(Weight::from_parts(61_940_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(9, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2508).saturating_mul(n.into()))
 	}
 }
 
@@ -333,27 +470,41 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 impl WeightInfo for () {
 	/// Storage: `Vector::WhitelistedDomains` (r:1 w:0)
 	/// Proof: `Vector::WhitelistedDomains` (`max_values`: Some(1), `max_size`: Some(40002), added: 40497, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::DestinationChainFrozen` (r:1 w:0)
+	/// Proof: `Vector::DestinationChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 102400]`.
 	fn send_message_arbitrary_message(_l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `357`
 		//  Estimated: `41487`
-		// Minimum execution time: 19_199_000 picoseconds.
+		// Estimated from the storage accesses, not benchmarked.
 		Weight::from_parts(20_257_871, 41487)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 	}
 	/// Storage: `Vector::WhitelistedDomains` (r:1 w:0)
 	/// Proof: `Vector::WhitelistedDomains` (`max_values`: Some(1), `max_size`: Some(40002), added: 40497, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::DestinationChainFrozen` (r:1 w:1)
+	/// Proof: `Vector::DestinationChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::FlowLimits` (r:1 w:0)
+	/// Proof: `Vector::FlowLimits` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::FlowUsages` (r:1 w:1)
+	/// Proof: `Vector::FlowUsages` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::BridgedAssets` (r:1 w:0)
+	/// Proof: `Vector::BridgedAssets` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	fn send_message_fungible_token() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `586`
 		//  Estimated: `41487`
-		// Minimum execution time: 87_666_000 picoseconds.
-		Weight::from_parts(88_852_000, 41487)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(131_052_000, 41487)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Vector::SyncCommitteePoseidons` (r:0 w:1)
 	/// Proof: `Vector::SyncCommitteePoseidons` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
@@ -406,6 +557,16 @@ impl WeightInfo for () {
 		Weight::from_parts(12_985_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Vector::DestinationChainFrozen` (r:0 w:1)
+	/// Proof: `Vector::DestinationChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	fn destination_chain_froze() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(12_985_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 	/// Storage: `Vector::ConfigurationStorage` (r:1 w:0)
 	/// Proof: `Vector::ConfigurationStorage` (`max_values`: Some(1), `max_size`: Some(10), added: 505, mode: `MaxEncodedLen`)
 	/// Storage: `Vector::FunctionIds` (r:1 w:0)
@@ -424,14 +585,16 @@ impl WeightInfo for () {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Vector::Timestamps` (r:0 w:1)
 	/// Proof: `Vector::Timestamps` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::NextHeaderSlot` (r:0 w:1)
+	/// Proof: `Vector::NextHeaderSlot` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
 	fn fulfill_call_step() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2599`
 		//  Estimated: `11488`
-		// Minimum execution time: 25_210_559_000 picoseconds.
+		// Estimated from the storage accesses, not benchmarked.
 		Weight::from_parts(25_282_049_000, 11488)
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Vector::ConfigurationStorage` (r:1 w:0)
 	/// Proof: `Vector::ConfigurationStorage` (`max_values`: Some(1), `max_size`: Some(10), added: 505, mode: `MaxEncodedLen`)
@@ -458,20 +621,30 @@ impl WeightInfo for () {
 	/// Proof: `Vector::WhitelistedDomains` (`max_values`: Some(1), `max_size`: Some(40002), added: 40497, mode: `MaxEncodedLen`)
 	/// Storage: `Vector::Broadcasters` (r:1 w:0)
 	/// Proof: `Vector::Broadcasters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
-	/// Storage: `Vector::SourceChainFrozen` (r:1 w:0)
+	/// Storage: `Vector::SourceChainFrozen` (r:1 w:1)
 	/// Proof: `Vector::SourceChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
 	/// Storage: `Vector::ExecutionStateRoots` (r:1 w:0)
 	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::FlowLimits` (r:1 w:0)
+	/// Proof: `Vector::FlowLimits` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::FlowUsages` (r:1 w:1)
+	/// Proof: `Vector::FlowUsages` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::BridgedAssets` (r:1 w:0)
+	/// Proof: `Vector::BridgedAssets` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	fn execute_fungible_token() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `672`
 		//  Estimated: `41487`
-		// Minimum execution time: 202_005_000 picoseconds.
-		Weight::from_parts(204_185_000, 41487)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(251_885_000, 41487)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Vector::MessageStatus` (r:1 w:1)
 	/// Proof: `Vector::MessageStatus` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
@@ -575,13 +748,109 @@ impl WeightInfo for () {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Vector::Timestamps` (r:0 w:1)
 	/// Proof: `Vector::Timestamps` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::NextHeaderSlot` (r:0 w:1)
+	/// Proof: `Vector::NextHeaderSlot` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
 	fn fulfill() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `614`
 		//  Estimated: `6020`
-		// Minimum execution time: 532_131_000_000 picoseconds.
+		// Estimated from the storage accesses, not benchmarked.
 		Weight::from_parts(537_965_000_000, 6020)
 			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::BridgedAssets` (r:0 w:1)
+	/// Proof: `Vector::BridgedAssets` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	fn set_bridged_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `3675`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(10_000_000, 3675)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::FlowUsages` (r:0 w:1)
+	/// Proof: `Vector::FlowUsages` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::FlowLimits` (r:0 w:1)
+	/// Proof: `Vector::FlowLimits` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn set_flow_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(11_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Vector::NextHeaderSlot` (r:1 w:1)
+	/// Proof: `Vector::NextHeaderSlot` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Headers` (r:0 w:1)
+	/// Proof: `Vector::Headers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ExecutionStateRoots` (r:0 w:1)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Timestamps` (r:0 w:1)
+	/// Proof: `Vector::Timestamps` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn prune_header() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `3481`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(13_000_000, 3481)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Vector::SyncCommitteePoseidons` (r:0 w:1)
+	/// Proof: `Vector::SyncCommitteePoseidons` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SyncCommitteeHashes` (r:0 w:1)
+	/// Proof: `Vector::SyncCommitteeHashes` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn prune_sync_committee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(7_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Vector::Headers` (r:1 w:1)
+	/// Proof: `Vector::Headers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ExecutionStateRoots` (r:0 w:1)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Timestamps` (r:0 w:1)
+	/// Proof: `Vector::Timestamps` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn prune_legacy_header() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `171`
+		//  Estimated: `3505`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(15_000_000, 3505)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Vector::ExecutionStateRoots` (r:1 w:0)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Broadcasters` (r:1 w:0)
+	/// Proof: `Vector::Broadcasters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::WhitelistedDomains` (r:1 w:0)
+	/// Proof: `Vector::WhitelistedDomains` (`max_values`: Some(1), `max_size`: Some(40002), added: 40497, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceChainFrozen` (r:1 w:0)
+	/// Proof: `Vector::SourceChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::MessageStatus` (r:64 w:64)
+	/// Proof: `Vector::MessageStatus` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 64]`.
+	/// The range of component `l` is `[0, 102400]`.
+	fn execute_batch(n: u32, l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `494 + n * (32 ±0)`
+		//  Estimated: `41487 + n * (2508 ±0)`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(68_312_000, 41487)
+			.saturating_add(Weight::from_parts(61_940_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(9, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2508).saturating_mul(n.into()))
 	}
 }
\ No newline at end of file
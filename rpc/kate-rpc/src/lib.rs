@@ -1,35 +1,116 @@
-use avail_base::metrics::avail::{MetricObserver, ObserveKind};
+use avail_base::{
+	header_extension::{DataMultiProof, Namespace},
+	metrics::avail::{MetricObserver, ObserveKind},
+};
 use avail_core::{
-	data_proof::ProofResponse, header::HeaderExtension, traits::ExtendedHeader, OpaqueExtrinsic,
+	data_proof::ProofResponse, header::HeaderExtension, traits::ExtendedHeader, AppId,
+	OpaqueExtrinsic,
 };
 use da_runtime::apis::{DataAvailApi, KateApi as RTKateApi};
-use da_runtime::kate::{GDataProof, GRow};
+use da_runtime::kate::{Error as RTKateError, GDataProof, GMultiProofs, GRow};
 use kate::com::Cell;
 
 use frame_support::BoundedVec;
 use frame_system::limits::BlockLength;
+use futures::{Future, FutureExt as _, StreamExt as _};
 use jsonrpsee::{
-	core::{async_trait, RpcResult},
+	core::{async_trait, RpcResult, SubscriptionResult},
 	proc_macros::rpc,
-	types::error::{ErrorCode, ErrorObject},
+	types::error::{ErrorCode, ErrorObject, ErrorObjectOwned},
+	PendingSubscriptionSink, SubscriptionMessage,
 };
-use sc_client_api::BlockBackend;
-use sp_api::{ApiRef, ProvideRuntimeApi};
+use rayon::iter::{IntoParallelIterator as _, ParallelIterator as _};
+use sc_client_api::{BlockBackend, BlockchainEvents};
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, ApiRef, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H256};
 use sp_runtime::{
 	generic::SignedBlock,
-	traits::{Block as BlockT, ConstU32, Header},
+	traits::{Block as BlockT, ConstU32, Hash as _, HashingFor, Header, NumberFor},
 };
-use std::{marker::PhantomData, marker::Sync, sync::Arc};
+use std::{iter, marker::PhantomData, marker::Sync, sync::Arc};
 
 pub type HashOf<Block> = <Block as BlockT>::Hash;
 pub type MaxRows = ConstU32<64>;
 pub type Rows = BoundedVec<u32, MaxRows>;
 pub type MaxCells = ConstU32<10_000>;
 pub type Cells = BoundedVec<Cell, MaxCells>;
+pub type MaxBatchBlocks = ConstU32<64>;
+pub type ProofBatch<Hash> = BoundedVec<(Hash, Cells), MaxBatchBlocks>;
+pub type MaxTransactions = ConstU32<1_024>;
+pub type TransactionIndices = BoundedVec<u32, MaxTransactions>;
 
 pub mod metrics;
 
+/// Kate data of a finalized block, pushed to the subscribers of `kate_subscribeFinalized*`.
+///
+/// Exactly one of `data` and `error` is set, so a block which fails to be queried does not end
+/// the subscription.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FinalizedData<Hash, T> {
+	pub block_hash: Hash,
+	pub block_number: u32,
+	pub data: Option<T>,
+	pub error: Option<ErrorObjectOwned>,
+}
+
+/// Proofs of the cells requested for one block of a `kate_queryProofBatch` call.
+///
+/// Exactly one of `proofs` and `error` is set.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockProofs<Hash> {
+	pub block_hash: Hash,
+	pub proofs: Option<KateResponse<Hash, Vec<GDataProof>>>,
+	pub error: Option<ErrorObjectOwned>,
+}
+
+/// Data submission of a block, as returned by `kate_queryBlobs`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Blob {
+	pub tx_index: u32,
+	pub namespace: Option<Namespace>,
+	pub data: Bytes,
+}
+
+/// Response of a Kate query which opted in to unfinalized blocks.
+///
+/// Responses served from finalized blocks are serialized as their bare data, the same as for
+/// queries which did not opt in.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KateResponse<Hash, T> {
+	Unfinalized(UnfinalizedData<Hash, T>),
+	Finalized(T),
+}
+
+impl<Hash, T> KateResponse<Hash, T> {
+	pub fn is_finalized(&self) -> bool {
+		matches!(self, Self::Finalized(_))
+	}
+
+	pub fn into_data(self) -> T {
+		match self {
+			Self::Unfinalized(unfinalized) => unfinalized.data,
+			Self::Finalized(data) => data,
+		}
+	}
+}
+
+/// Kate data of a best-chain block which is not finalized yet, so it may still be reverted.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnfinalizedData<Hash, T> {
+	pub block_hash: Hash,
+	pub block_number: u32,
+	/// Always `true`, it tells these responses apart from finalized ones.
+	pub unfinalized: bool,
+	pub data: T,
+}
+
 /// # TODO
 /// - [ ] Update type definitions for RPCs in our subxt & explorer.
 #[rpc(client, server)]
@@ -37,15 +118,43 @@ pub trait KateApi<Block>
 where
 	Block: BlockT,
 {
+	/// Queries `rows` of the extended grid.
+	///
+	/// Queries on blocks which are not finalized yet are rejected, unless `allow_unfinalized`
+	/// is set and the node runs with `--kate-allow-unfinalized`. The same applies to the other
+	/// `kate_query*` methods which take `allow_unfinalized`.
 	#[method(name = "kate_queryRows")]
-	async fn query_rows(&self, rows: Rows, at: Option<HashOf<Block>>) -> RpcResult<Vec<GRow>>;
+	async fn query_rows(
+		&self,
+		rows: Rows,
+		at: Option<HashOf<Block>>,
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, Vec<GRow>>>;
 
 	#[method(name = "kate_queryProof")]
 	async fn query_proof(
 		&self,
 		cells: Cells,
 		at: Option<HashOf<Block>>,
-	) -> RpcResult<Vec<GDataProof>>;
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, Vec<GDataProof>>>;
+
+	#[method(name = "kate_queryProofBatch")]
+	async fn query_proof_batch(
+		&self,
+		batch: ProofBatch<HashOf<Block>>,
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<Vec<BlockProofs<HashOf<Block>>>>;
+
+	/// Queries multiproofs of `cells` of the multiproof grid, which partitions the extended grid
+	/// into cell blocks.
+	#[method(name = "kate_queryMultiProof")]
+	async fn query_multiproof(
+		&self,
+		cells: Cells,
+		at: Option<HashOf<Block>>,
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, GMultiProofs>>;
 
 	#[method(name = "kate_blockLength")]
 	async fn query_block_length(&self, at: Option<HashOf<Block>>) -> RpcResult<BlockLength>;
@@ -55,36 +164,200 @@ where
 		&self,
 		transaction_index: u32,
 		at: Option<HashOf<Block>>,
-	) -> RpcResult<ProofResponse>;
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, ProofResponse>>;
+
+	/// Queries the data proof of the extrinsic with hash `tx_hash` in block `at`.
+	#[method(name = "kate_queryDataProofByTxHash")]
+	async fn query_data_proof_by_tx_hash(
+		&self,
+		tx_hash: HashOf<Block>,
+		at: Option<HashOf<Block>>,
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, ProofResponse>>;
+
+	/// Queries the data proof of the data submission in block `at` whose hash is `data_hash`,
+	/// as reported by its `DataSubmitted` event.
+	#[method(name = "kate_queryDataProofByDataHash")]
+	async fn query_data_proof_by_data_hash(
+		&self,
+		data_hash: H256,
+		at: Option<HashOf<Block>>,
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, ProofResponse>>;
+
+	/// Queries a single proof of the data submissions at `transaction_indices` in block `at`,
+	/// sharing the hashes common to several submissions.
+	#[method(name = "kate_queryDataProofs")]
+	async fn query_data_proofs(
+		&self,
+		transaction_indices: TransactionIndices,
+		at: Option<HashOf<Block>>,
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, DataMultiProof>>;
+
+	/// Queries a proof that no data submission of block `at` has `leaf_hash`
+	/// (`keccak(keccak(data))`) as leaf of the submitted tree.
+	///
+	/// The proof contains every leaf of that tree.
+	#[method(name = "kate_queryDataNonInclusionProof")]
+	async fn query_data_non_inclusion_proof(
+		&self,
+		leaf_hash: H256,
+		at: Option<HashOf<Block>>,
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, DataMultiProof>>;
+
+	#[method(name = "kate_queryAppData")]
+	async fn query_app_data(
+		&self,
+		app_id: AppId,
+		at: Option<HashOf<Block>>,
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, Vec<(u32, GRow)>>>;
+
+	/// Queries the data submissions of `app_id` in block `at`, only those tagged with
+	/// `namespace` if it is set.
+	#[method(name = "kate_queryBlobs")]
+	async fn query_blobs(
+		&self,
+		app_id: AppId,
+		namespace: Option<Namespace>,
+		at: Option<HashOf<Block>>,
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, Vec<Blob>>>;
+
+	#[subscription(
+		name = "kate_subscribeFinalizedRows",
+		unsubscribe = "kate_unsubscribeFinalizedRows",
+		item = FinalizedData<HashOf<Block>, Vec<GRow>>
+	)]
+	async fn subscribe_finalized_rows(&self, rows: Rows) -> SubscriptionResult;
+
+	#[subscription(
+		name = "kate_subscribeFinalizedCells",
+		unsubscribe = "kate_unsubscribeFinalizedCells",
+		item = FinalizedData<HashOf<Block>, Vec<GDataProof>>
+	)]
+	async fn subscribe_finalized_cells(&self, cells: Cells) -> SubscriptionResult;
 }
 
 #[allow(clippy::type_complexity)]
 pub struct Kate<Client, Block: BlockT> {
 	client: Arc<Client>,
 	max_cells_size: usize,
+	allow_unfinalized: bool,
 	_block: PhantomData<Block>,
 }
 
+impl<Client, Block: BlockT> Clone for Kate<Client, Block> {
+	fn clone(&self) -> Self {
+		Self {
+			client: self.client.clone(),
+			max_cells_size: self.max_cells_size,
+			allow_unfinalized: self.allow_unfinalized,
+			_block: PhantomData,
+		}
+	}
+}
+
 impl<Client, Block: BlockT> Kate<Client, Block> {
-	pub fn new(client: Arc<Client>, max_cells_size: usize) -> Self {
+	pub fn new(client: Arc<Client>, max_cells_size: usize, allow_unfinalized: bool) -> Self {
 		Self {
 			client,
 			max_cells_size,
+			allow_unfinalized,
 			_block: PhantomData,
 		}
 	}
 }
 
 /// Error type of this RPC api.
+///
+/// Each variant maps to its own JSON-RPC error code, and errors carry an [`ErrorData`] as
+/// their `data` when there is something useful to report.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Error {
-	/// The transaction was not decodable.
+	/// Internal error of the node.
 	KateRPCError,
+	/// The requested block is not finalized yet.
+	BlockNotFinalized,
+	/// The requested block is unknown to the node.
+	MissingBlock,
+	/// The requested block has empty commitments, so it has no grid.
+	EmptyCommitments,
+	/// More cells were requested than allowed by `--kate-max-cells-size`.
+	CellsLimitExceeded,
+	/// The runtime Kate API failed.
+	KateRuntime,
+	/// The requested transaction is not part of the block.
+	MissingTransaction,
+	/// The leaf whose absence was requested is part of the block.
+	IncludedLeaf,
+	/// The runtime of the requested block does not support the query.
+	UnsupportedRuntime,
+	/// The requested block is not part of the best chain.
+	NonCanonicalBlock,
 }
 
 impl From<Error> for i32 {
 	fn from(e: Error) -> i32 {
 		match e {
 			Error::KateRPCError => 1,
+			Error::BlockNotFinalized => 2,
+			Error::MissingBlock => 3,
+			Error::EmptyCommitments => 4,
+			Error::CellsLimitExceeded => 5,
+			Error::KateRuntime => 6,
+			Error::MissingTransaction => 7,
+			Error::IncludedLeaf => 8,
+			Error::UnsupportedRuntime => 9,
+			Error::NonCanonicalBlock => 10,
+		}
+	}
+}
+
+/// Structured `data` of the errors of this RPC api.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorData<Hash> {
+	/// Block the request failed on.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub block_hash: Option<Hash>,
+	/// Number of requested cells.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub requested: Option<usize>,
+	/// Maximum number of cells per request.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub limit: Option<usize>,
+	/// Error returned by the runtime Kate API.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub kate_error: Option<RTKateError>,
+}
+
+impl<Hash> ErrorData<Hash> {
+	fn block(block_hash: Hash) -> Self {
+		Self {
+			block_hash: Some(block_hash),
+			requested: None,
+			limit: None,
+			kate_error: None,
+		}
+	}
+
+	fn cells_limit(requested: usize, limit: usize) -> Self {
+		Self {
+			block_hash: None,
+			requested: Some(requested),
+			limit: Some(limit),
+			kate_error: None,
+		}
+	}
+
+	fn kate(block_hash: Hash, kate_error: RTKateError) -> Self {
+		Self {
+			kate_error: Some(kate_error),
+			..Self::block(block_hash)
 		}
 	}
 }
@@ -99,6 +372,16 @@ macro_rules! internal_err {
 	}}
 }
 
+macro_rules! rpc_err {
+	($err:expr, $data:expr, $($arg:tt)*) => {{
+		ErrorObject::owned(
+			i32::from($err),
+			format!($($arg)*),
+			Some($data)
+		)
+	}}
+}
+
 // ApiRef<'_, dyn ApiExt<Block>>,
 
 type Opaques<B> = Vec<<B as BlockT>::Extrinsic>;
@@ -117,6 +400,7 @@ where
 	fn scope(
 		&self,
 		at: Option<Block::Hash>,
+		allow_unfinalized: Option<bool>,
 	) -> RpcResult<(
 		Api<'_, Client, Block>,
 		<Block as BlockT>::Hash,
@@ -126,7 +410,13 @@ where
 		<Block as BlockT>::Header,
 	)> {
 		let at = self.at_or_best(at);
-		let block = self.get_finalized_block(Some(at))?.block;
+		let block = if self.allow_unfinalized && allow_unfinalized.unwrap_or(false) {
+			let block = self.get_block(Some(at))?;
+			self.ensure_block_canonical(&block)?;
+			block.block
+		} else {
+			self.get_finalized_block(Some(at))?.block
+		};
 		let number: u32 = (*block.header().number())
 			.try_into()
 			.map_err(|_| ErrorCode::InvalidParams)?;
@@ -144,12 +434,38 @@ where
 		at.unwrap_or_else(|| self.client.info().best_hash)
 	}
 
+	/// Whether `hash` is the block at `number` of the best chain, which contains every finalized
+	/// block.
+	fn is_canonical(&self, hash: Block::Hash, number: NumberFor<Block>) -> bool {
+		matches!(self.client.hash(number), Ok(Some(canonical)) if canonical == hash)
+	}
+
+	fn ensure_block_canonical(&self, block: &SignedBlock<Block>) -> RpcResult<()> {
+		let block_header = block.block.header();
+		let (block_hash, block_number) = (block_header.hash(), *block_header.number());
+
+		if !self.is_canonical(block_hash, block_number) {
+			return Err(rpc_err!(
+				Error::NonCanonicalBlock,
+				ErrorData::block(block_hash),
+				"Requested block {block_hash} is not part of the best chain"
+			));
+		}
+
+		Ok(())
+	}
+
 	fn ensure_block_finalized(&self, block: &SignedBlock<Block>) -> RpcResult<()> {
 		let block_header = block.block.header();
 		let (block_hash, block_number) = (block_header.hash(), *block_header.number());
 
-		if self.client.info().finalized_number < block_number {
-			return Err(internal_err!(
+		// Blocks of forks below the finalized head will never be finalized.
+		if self.client.info().finalized_number < block_number
+			|| !self.is_canonical(block_hash, block_number)
+		{
+			return Err(rpc_err!(
+				Error::BlockNotFinalized,
+				ErrorData::block(block_hash),
 				"Requested block {block_hash} is not finalized"
 			));
 		}
@@ -162,7 +478,14 @@ where
 		self.client
 			.block(at)
 			.map_err(|e| internal_err!("Invalid block number: {:?}", e))?
-			.ok_or_else(|| internal_err!("Missing block {}", at))
+			.ok_or_else(|| {
+				rpc_err!(
+					Error::MissingBlock,
+					ErrorData::block(at),
+					"Missing block {}",
+					at
+				)
+			})
 	}
 
 	fn get_finalized_block(&self, at: Option<Block::Hash>) -> RpcResult<SignedBlock<Block>> {
@@ -170,6 +493,207 @@ where
 		self.ensure_block_finalized(&signed_block)?;
 		Ok(signed_block)
 	}
+
+	/// Wraps `data` of block `at`, marking it if the block is not finalized.
+	fn respond<T>(&self, at: Block::Hash, number: u32, data: T) -> KateResponse<Block::Hash, T> {
+		let block_number = number.into();
+		if self.client.info().finalized_number >= block_number
+			&& self.is_canonical(at, block_number)
+		{
+			return KateResponse::Finalized(data);
+		}
+
+		KateResponse::Unfinalized(UnfinalizedData {
+			block_hash: at,
+			block_number: number,
+			unfinalized: true,
+			data,
+		})
+	}
+
+	fn ensure_cells_limit(&self, cells_len: usize) -> RpcResult<()> {
+		if cells_len > self.max_cells_size {
+			return Err(
+				rpc_err!(
+					Error::CellsLimitExceeded,
+					ErrorData::<Block::Hash>::cells_limit(cells_len, self.max_cells_size),
+					"Cannot query ({}) more than {} amount of cells per request. Either increase the max cells size (--kate-max-cells-size) or query less amount of cells per request.",
+					cells_len,
+					self.max_cells_size
+				)
+			);
+		}
+
+		Ok(())
+	}
+
+	fn ensure_commitments(at: Block::Hash, header: &Block::Header) -> RpcResult<()> {
+		match header.extension() {
+			HeaderExtension::V3(ext) => {
+				if ext.commitment.commitment.is_empty() {
+					return Err(rpc_err!(
+						Error::EmptyCommitments,
+						ErrorData::block(at),
+						"Requested block {at} has empty commitments"
+					));
+				}
+			},
+		};
+
+		Ok(())
+	}
+
+	fn kate_runtime_err(at: Block::Hash, kate_err: RTKateError) -> ErrorObjectOwned {
+		rpc_err!(
+			Error::KateRuntime,
+			ErrorData::kate(at, kate_err.clone()),
+			"Failed API: {kate_err:?}"
+		)
+	}
+}
+
+impl<Client, Block> Kate<Client, Block>
+where
+	Block: BlockT,
+	<Block as BlockT>::Header: ExtendedHeader<Extension = HeaderExtension>,
+	Client: Send + Sync + 'static,
+	Client: HeaderBackend<Block> + BlockchainEvents<Block>,
+{
+	/// Accepts `pending` and sends the result of `query` for every newly finalized block, until
+	/// the subscriber goes away.
+	///
+	/// Blocks without commitments are skipped, as they have no grid to query. Blocks which fail
+	/// to be queried are sent with their error instead of their data.
+	async fn pipe_finalized<T, Q, Fut>(
+		&self,
+		pending: PendingSubscriptionSink,
+		query: Q,
+	) -> SubscriptionResult
+	where
+		T: Serialize,
+		Q: Fn(Block::Hash) -> Fut,
+		Fut: Future<Output = RpcResult<T>>,
+	{
+		let sink = pending.accept().await?;
+		let mut finality_stream = self.client.finality_notification_stream().fuse();
+
+		loop {
+			let notification = futures::select! {
+				_ = sink.closed().fuse() => break,
+				notification = finality_stream.next() => match notification {
+					Some(notification) => notification,
+					None => break,
+				},
+			};
+
+			// `tree_route` contains the blocks implicitly finalized by this notification.
+			let finalized = notification
+				.tree_route
+				.iter()
+				.copied()
+				.chain(iter::once(notification.hash));
+
+			for block_hash in finalized {
+				let Ok(Some(header)) = self.client.header(block_hash) else {
+					continue;
+				};
+				let has_commitments = match header.extension() {
+					HeaderExtension::V3(ext) => !ext.commitment.commitment.is_empty(),
+				};
+				if !has_commitments {
+					continue;
+				}
+
+				let block_number: u32 = (*header.number())
+					.try_into()
+					.map_err(|_| ErrorCode::InvalidParams)?;
+				let (data, error) = match query(block_hash).await {
+					Ok(data) => (Some(data), None),
+					Err(error) => (None, Some(error)),
+				};
+				let finalized_data = FinalizedData {
+					block_hash,
+					block_number,
+					data,
+					error,
+				};
+				sink.send(SubscriptionMessage::from_json(&finalized_data)?)
+					.await?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl<Client, Block> Kate<Client, Block>
+where
+	Block: BlockT<Extrinsic = OpaqueExtrinsic>,
+	<Block as BlockT>::Header: ExtendedHeader<Extension = HeaderExtension>,
+	Client: Send + Sync + 'static,
+	Client: HeaderBackend<Block> + ProvideRuntimeApi<Block> + BlockBackend<Block>,
+	Client::Api: DataAvailApi<Block> + RTKateApi<Block>,
+{
+	fn proof(
+		&self,
+		cells: Cells,
+		at: Option<HashOf<Block>>,
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, Vec<GDataProof>>> {
+		let (api, at, number, block_len, extrinsics, header) = self.scope(at, allow_unfinalized)?;
+		Self::ensure_commitments(at, &header)?;
+
+		let cells = cells
+			.into_iter()
+			.map(|cell| (cell.row.0, cell.col.0))
+			.collect::<Vec<_>>();
+		let proof = api
+			.proof(at, number, extrinsics, block_len, cells)
+			.map_err(|kate_err| internal_err!("KateApi::proof failed: {kate_err:?}"))?
+			.map_err(|api_err| Self::kate_runtime_err(at, api_err))?;
+
+		Ok(self.respond(at, number, proof))
+	}
+
+	/// Fails unless the runtime of block `at` implements `KateApi` at `version` or later, which
+	/// is required by `method`.
+	fn ensure_kate_api(
+		api: &Client::Api,
+		at: HashOf<Block>,
+		version: u32,
+		method: &str,
+	) -> RpcResult<()> {
+		let supported = api
+			.has_api_with::<dyn RTKateApi<Block>, _>(at, |v| v >= version)
+			.map_err(|e| internal_err!("KateApi version at block {at:?}: {e:?}"))?;
+		if !supported {
+			return Err(rpc_err!(
+				Error::UnsupportedRuntime,
+				ErrorData::block(at),
+				"Runtime of block {at:?} does not support KateApi::{method}"
+			));
+		}
+
+		Ok(())
+	}
+
+	fn data_proof(
+		api: &Client::Api,
+		at: HashOf<Block>,
+		number: u32,
+		extrinsics: Opaques<Block>,
+		tx_idx: u32,
+	) -> RpcResult<ProofResponse> {
+		api.data_proof(at, number, extrinsics, tx_idx)
+			.map_err(|e| internal_err!("KateApi::data_proof failed: {e:?}"))?
+			.ok_or_else(|| {
+				rpc_err!(
+					Error::MissingTransaction,
+					ErrorData::block(at),
+					"Cannot fetch tx data at tx index {tx_idx:?} at block {at:?}"
+				)
+			})
+	}
 }
 
 #[async_trait]
@@ -179,65 +703,104 @@ where
 	<Block as BlockT>::Header: ExtendedHeader<Extension = HeaderExtension>,
 	Client: Send + Sync + 'static,
 	Client: HeaderBackend<Block> + ProvideRuntimeApi<Block> + BlockBackend<Block>,
+	Client: BlockchainEvents<Block>,
 	Client::Api: DataAvailApi<Block> + RTKateApi<Block>,
 {
-	async fn query_rows(&self, rows: Rows, at: Option<HashOf<Block>>) -> RpcResult<Vec<GRow>> {
+	async fn query_rows(
+		&self,
+		rows: Rows,
+		at: Option<HashOf<Block>>,
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, Vec<GRow>>> {
 		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryRows);
 
-		let (api, at, number, block_len, extrinsics, header) = self.scope(at)?;
+		let (api, at, number, block_len, extrinsics, header) = self.scope(at, allow_unfinalized)?;
 
-		match header.extension() {
-			HeaderExtension::V3(ext) => {
-				if ext.commitment.commitment.is_empty() {
-					return Err(internal_err!("Requested block {at} has empty commitments"));
-				}
-			},
-		};
+		Self::ensure_commitments(at, &header)?;
 
 		let grid_rows = api
 			.rows(at, number, extrinsics, block_len, rows.into())
 			.map_err(|kate_err| internal_err!("Failed Kate rows: {kate_err:?}"))?
-			.map_err(|api_err| internal_err!("Failed API: {api_err:?}"))?;
+			.map_err(|api_err| Self::kate_runtime_err(at, api_err))?;
 
-		Ok(grid_rows)
+		Ok(self.respond(at, number, grid_rows))
 	}
 
 	async fn query_proof(
 		&self,
 		cells: Cells,
 		at: Option<HashOf<Block>>,
-	) -> RpcResult<Vec<GDataProof>> {
-		if cells.len() > self.max_cells_size {
-			return Err(
-				internal_err!(
-					"Cannot query ({}) more than {} amount of cells per request. Either increase the max cells size (--kate-max-cells-size) or query less amount of cells per request.",
-					cells.len(),
-					self.max_cells_size
-				)
-			);
-		}
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, Vec<GDataProof>>> {
+		self.ensure_cells_limit(cells.len())?;
 
 		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryProof);
 
-		let (api, at, number, block_len, extrinsics, header) = self.scope(at)?;
-		match header.extension() {
-			HeaderExtension::V3(ext) => {
-				if ext.commitment.commitment.is_empty() {
-					return Err(internal_err!("Requested block {at} has empty commitments"));
-				}
-			},
-		};
+		self.proof(cells, at, allow_unfinalized)
+	}
+
+	async fn query_proof_batch(
+		&self,
+		batch: ProofBatch<HashOf<Block>>,
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<Vec<BlockProofs<HashOf<Block>>>> {
+		let total_cells = batch.iter().map(|(_, cells)| cells.len()).sum();
+		self.ensure_cells_limit(total_cells)?;
+
+		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryProofBatch);
+
+		// Proofs are computed on the rayon pool, so they must not block an async worker.
+		let kate = self.clone();
+		let block_proofs = tokio::task::spawn_blocking(move || {
+			batch
+				.into_inner()
+				.into_par_iter()
+				.map(|(block_hash, cells)| {
+					match kate.proof(cells, Some(block_hash), allow_unfinalized) {
+						Ok(proofs) => BlockProofs {
+							block_hash,
+							proofs: Some(proofs),
+							error: None,
+						},
+						Err(error) => BlockProofs {
+							block_hash,
+							proofs: None,
+							error: Some(error),
+						},
+					}
+				})
+				.collect::<Vec<_>>()
+		})
+		.await
+		.map_err(|e| internal_err!("Proof batch failed: {e:?}"))?;
+
+		Ok(block_proofs)
+	}
+
+	async fn query_multiproof(
+		&self,
+		cells: Cells,
+		at: Option<HashOf<Block>>,
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, GMultiProofs>> {
+		self.ensure_cells_limit(cells.len())?;
+
+		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryMultiProof);
+
+		let (api, at, number, block_len, extrinsics, header) = self.scope(at, allow_unfinalized)?;
+		Self::ensure_kate_api(&api, at, 3, "multiproof")?;
+		Self::ensure_commitments(at, &header)?;
 
 		let cells = cells
 			.into_iter()
 			.map(|cell| (cell.row.0, cell.col.0))
 			.collect::<Vec<_>>();
-		let proof = api
-			.proof(at, number, extrinsics, block_len, cells)
-			.map_err(|kate_err| internal_err!("KateApi::proof failed: {kate_err:?}"))?
-			.map_err(|api_err| internal_err!("Failed API: {api_err:?}"))?;
+		let multiproofs = api
+			.multiproof(at, number, extrinsics, block_len, cells)
+			.map_err(|kate_err| internal_err!("KateApi::multiproof failed: {kate_err:?}"))?
+			.map_err(|api_err| Self::kate_runtime_err(at, api_err))?;
 
-		Ok(proof)
+		Ok(self.respond(at, number, multiproofs))
 	}
 
 	async fn query_block_length(&self, at: Option<HashOf<Block>>) -> RpcResult<BlockLength> {
@@ -256,18 +819,186 @@ where
 		&self,
 		tx_idx: u32,
 		at: Option<HashOf<Block>>,
-	) -> RpcResult<ProofResponse> {
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, ProofResponse>> {
 		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryDataProof);
 
 		// Calculate proof for block and tx index
-		let (api, at, number, _, extrinsics, _) = self.scope(at)?;
+		let (api, at, number, _, extrinsics, _) = self.scope(at, allow_unfinalized)?;
+		let proof = Self::data_proof(&api, at, number, extrinsics, tx_idx)?;
+
+		Ok(self.respond(at, number, proof))
+	}
+
+	async fn query_data_proof_by_tx_hash(
+		&self,
+		tx_hash: HashOf<Block>,
+		at: Option<HashOf<Block>>,
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, ProofResponse>> {
+		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryDataProofByTxHash);
+
+		let (api, at, number, _, extrinsics, _) = self.scope(at, allow_unfinalized)?;
+		let tx_idx = extrinsics
+			.iter()
+			.position(|ext| HashingFor::<Block>::hash_of(ext) == tx_hash)
+			.ok_or_else(|| {
+				rpc_err!(
+					Error::MissingTransaction,
+					ErrorData::block(at),
+					"Missing transaction {tx_hash:?} at block {at:?}"
+				)
+			})?;
+		let tx_idx = u32::try_from(tx_idx).map_err(|_| ErrorCode::InvalidParams)?;
+		let proof = Self::data_proof(&api, at, number, extrinsics, tx_idx)?;
+
+		Ok(self.respond(at, number, proof))
+	}
+
+	async fn query_data_proof_by_data_hash(
+		&self,
+		data_hash: H256,
+		at: Option<HashOf<Block>>,
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, ProofResponse>> {
+		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryDataProofByDataHash);
+
+		let (api, at, number, _, extrinsics, _) = self.scope(at, allow_unfinalized)?;
+		Self::ensure_kate_api(&api, at, 4, "data_submission_tx_idx")?;
+		let tx_idx = api
+			.data_submission_tx_idx(at, number, extrinsics.clone(), data_hash)
+			.map_err(|e| internal_err!("KateApi::data_submission_tx_idx failed: {e:?}"))?
+			.ok_or_else(|| {
+				rpc_err!(
+					Error::MissingTransaction,
+					ErrorData::block(at),
+					"Missing data submission {data_hash:?} at block {at:?}"
+				)
+			})?;
+		let proof = Self::data_proof(&api, at, number, extrinsics, tx_idx)?;
+
+		Ok(self.respond(at, number, proof))
+	}
+
+	async fn query_data_proofs(
+		&self,
+		tx_indices: TransactionIndices,
+		at: Option<HashOf<Block>>,
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, DataMultiProof>> {
+		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryDataProofs);
+
+		let (api, at, number, _, extrinsics, _) = self.scope(at, allow_unfinalized)?;
+		Self::ensure_kate_api(&api, at, 5, "data_proofs")?;
 		let proof = api
-			.data_proof(at, number, extrinsics, tx_idx)
-			.map_err(|e| internal_err!("KateApi::data_proof failed: {e:?}"))?
+			.data_proofs(at, number, extrinsics, tx_indices.into_inner())
+			.map_err(|e| internal_err!("KateApi::data_proofs failed: {e:?}"))?
+			.ok_or_else(|| {
+				rpc_err!(
+					Error::MissingTransaction,
+					ErrorData::block(at),
+					"Cannot fetch tx data of all the requested tx indices at block {at:?}"
+				)
+			})?;
+
+		Ok(self.respond(at, number, proof))
+	}
+
+	async fn query_data_non_inclusion_proof(
+		&self,
+		leaf_hash: H256,
+		at: Option<HashOf<Block>>,
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, DataMultiProof>> {
+		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryDataNonInclusionProof);
+
+		let (api, at, number, _, extrinsics, _) = self.scope(at, allow_unfinalized)?;
+		Self::ensure_kate_api(&api, at, 6, "data_non_inclusion_proof")?;
+		let proof = api
+			.data_non_inclusion_proof(at, number, extrinsics, leaf_hash)
+			.map_err(|e| internal_err!("KateApi::data_non_inclusion_proof failed: {e:?}"))?
 			.ok_or_else(|| {
-				internal_err!("Cannot fetch tx data at tx index {tx_idx:?} at block {at:?}")
+				rpc_err!(
+					Error::IncludedLeaf,
+					ErrorData::block(at),
+					"Leaf {leaf_hash:?} is included at block {at:?}"
+				)
 			})?;
 
-		Ok(proof)
+		Ok(self.respond(at, number, proof))
+	}
+
+	async fn query_app_data(
+		&self,
+		app_id: AppId,
+		at: Option<HashOf<Block>>,
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, Vec<(u32, GRow)>>> {
+		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryAppData);
+
+		let (api, at, number, block_len, extrinsics, header) = self.scope(at, allow_unfinalized)?;
+		Self::ensure_kate_api(&api, at, 2, "app_data")?;
+		Self::ensure_commitments(at, &header)?;
+
+		let app_rows = api
+			.app_data(at, number, extrinsics, block_len, app_id)
+			.map_err(|kate_err| internal_err!("KateApi::app_data failed: {kate_err:?}"))?
+			.map_err(|api_err| Self::kate_runtime_err(at, api_err))?;
+
+		Ok(self.respond(at, number, app_rows))
+	}
+
+	async fn query_blobs(
+		&self,
+		app_id: AppId,
+		namespace: Option<Namespace>,
+		at: Option<HashOf<Block>>,
+		allow_unfinalized: Option<bool>,
+	) -> RpcResult<KateResponse<HashOf<Block>, Vec<Blob>>> {
+		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryBlobs);
+
+		let (api, at, number, _, extrinsics, _) = self.scope(at, allow_unfinalized)?;
+		Self::ensure_kate_api(&api, at, 7, "data_submissions")?;
+		let blobs = api
+			.data_submissions(at, number, extrinsics, app_id, namespace)
+			.map_err(|e| internal_err!("KateApi::data_submissions failed: {e:?}"))?
+			.into_iter()
+			.map(|s| Blob {
+				tx_index: s.tx_index,
+				namespace: s.namespace,
+				data: s.data.into(),
+			})
+			.collect();
+
+		Ok(self.respond(at, number, blobs))
+	}
+
+	async fn subscribe_finalized_rows(
+		&self,
+		pending: PendingSubscriptionSink,
+		rows: Rows,
+	) -> SubscriptionResult {
+		self.pipe_finalized(pending, |at| {
+			self.query_rows(rows.clone(), Some(at), None)
+				.map(|rows| rows.map(KateResponse::into_data))
+		})
+		.await
+	}
+
+	async fn subscribe_finalized_cells(
+		&self,
+		pending: PendingSubscriptionSink,
+		cells: Cells,
+	) -> SubscriptionResult {
+		if let Err(limit_err) = self.ensure_cells_limit(cells.len()) {
+			pending.reject(limit_err).await;
+			return Ok(());
+		}
+
+		self.pipe_finalized(pending, |at| {
+			self.query_proof(cells.clone(), Some(at), None)
+				.map(|proofs| proofs.map(KateResponse::into_data))
+		})
+		.await
 	}
 }
@@ -1,18 +1,23 @@
-use crate::{Cells, HashOf, Kate, KateApiServer, ProofResponse, Rows};
+use crate::{
+	Blob, BlockProofs, Cells, HashOf, Kate, KateApiServer, ProofBatch, ProofResponse, Rows,
+	TransactionIndices,
+};
 
-use avail_core::{header::HeaderExtension, traits::ExtendedHeader, OpaqueExtrinsic};
+use avail_base::header_extension::{DataMultiProof, Namespace};
+use avail_core::{header::HeaderExtension, traits::ExtendedHeader, AppId, OpaqueExtrinsic};
 use da_runtime::apis::DataAvailApi;
 
 use crate::RTKateApi;
-use da_runtime::kate::{GDataProof, GRow};
+use da_runtime::kate::{GDataProof, GMultiProofs, GRow};
 use frame_system::limits::BlockLength;
 use jsonrpsee::{
 	core::{async_trait, RpcResult},
 	proc_macros::rpc,
 };
-use sc_client_api::BlockBackend;
+use sc_client_api::{BlockBackend, BlockchainEvents};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
+use sp_core::H256;
 use sp_runtime::traits::Block as BlockT;
 
 #[rpc(client, server)]
@@ -34,6 +39,19 @@ where
 		at: Option<HashOf<Block>>,
 	) -> RpcResult<(Vec<GDataProof>, u128)>;
 
+	#[method(name = "kate_queryProofBatchMetrics")]
+	async fn query_proof_batch_metrics(
+		&self,
+		batch: ProofBatch<HashOf<Block>>,
+	) -> RpcResult<(Vec<BlockProofs<HashOf<Block>>>, u128)>;
+
+	#[method(name = "kate_queryMultiProofMetrics")]
+	async fn query_multiproof_metrics(
+		&self,
+		cells: Cells,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(GMultiProofs, u128)>;
+
 	#[method(name = "kate_blockLengthMetrics")]
 	async fn query_block_length_metrics(
 		&self,
@@ -46,6 +64,49 @@ where
 		transaction_index: u32,
 		at: Option<HashOf<Block>>,
 	) -> RpcResult<(ProofResponse, u128)>;
+
+	#[method(name = "kate_queryDataProofByTxHashMetrics")]
+	async fn query_data_proof_by_tx_hash_metrics(
+		&self,
+		tx_hash: HashOf<Block>,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(ProofResponse, u128)>;
+
+	#[method(name = "kate_queryDataProofByDataHashMetrics")]
+	async fn query_data_proof_by_data_hash_metrics(
+		&self,
+		data_hash: H256,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(ProofResponse, u128)>;
+
+	#[method(name = "kate_queryDataProofsMetrics")]
+	async fn query_data_proofs_metrics(
+		&self,
+		transaction_indices: TransactionIndices,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(DataMultiProof, u128)>;
+
+	#[method(name = "kate_queryDataNonInclusionProofMetrics")]
+	async fn query_data_non_inclusion_proof_metrics(
+		&self,
+		leaf_hash: H256,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(DataMultiProof, u128)>;
+
+	#[method(name = "kate_queryAppDataMetrics")]
+	async fn query_app_data_metrics(
+		&self,
+		app_id: AppId,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(Vec<(u32, GRow)>, u128)>;
+
+	#[method(name = "kate_queryBlobsMetrics")]
+	async fn query_blobs_metrics(
+		&self,
+		app_id: AppId,
+		namespace: Option<Namespace>,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(Vec<Blob>, u128)>;
 }
 
 #[async_trait]
@@ -55,6 +116,7 @@ where
 	<Block as BlockT>::Header: ExtendedHeader<Extension = HeaderExtension>,
 	Client: Send + Sync + 'static,
 	Client: HeaderBackend<Block> + ProvideRuntimeApi<Block> + BlockBackend<Block>,
+	Client: BlockchainEvents<Block>,
 	Client::Api: DataAvailApi<Block> + RTKateApi<Block>,
 {
 	async fn query_rows_metrics(
@@ -63,10 +125,10 @@ where
 		at: Option<HashOf<Block>>,
 	) -> RpcResult<(Vec<GRow>, u128)> {
 		let start = std::time::Instant::now();
-		let result = self.query_rows(rows, at).await;
+		let result = self.query_rows(rows, at, None).await;
 		let elapsed = start.elapsed();
 
-		result.map(|r| (r, elapsed.as_micros()))
+		result.map(|r| (r.into_data(), elapsed.as_micros()))
 	}
 
 	async fn query_proof_metrics(
@@ -75,12 +137,35 @@ where
 		at: Option<HashOf<Block>>,
 	) -> RpcResult<(Vec<GDataProof>, u128)> {
 		let start = std::time::Instant::now();
-		let result = self.query_proof(cells, at).await;
+		let result = self.query_proof(cells, at, None).await;
+		let elapsed = start.elapsed();
+
+		result.map(|r| (r.into_data(), elapsed.as_micros()))
+	}
+
+	async fn query_proof_batch_metrics(
+		&self,
+		batch: ProofBatch<HashOf<Block>>,
+	) -> RpcResult<(Vec<BlockProofs<HashOf<Block>>>, u128)> {
+		let start = std::time::Instant::now();
+		let result = self.query_proof_batch(batch, None).await;
 		let elapsed = start.elapsed();
 
 		result.map(|r| (r, elapsed.as_micros()))
 	}
 
+	async fn query_multiproof_metrics(
+		&self,
+		cells: Cells,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(GMultiProofs, u128)> {
+		let start = std::time::Instant::now();
+		let result = self.query_multiproof(cells, at, None).await;
+		let elapsed = start.elapsed();
+
+		result.map(|r| (r.into_data(), elapsed.as_micros()))
+	}
+
 	async fn query_block_length_metrics(
 		&self,
 		at: Option<HashOf<Block>>,
@@ -98,9 +183,86 @@ where
 		at: Option<HashOf<Block>>,
 	) -> RpcResult<(ProofResponse, u128)> {
 		let start = std::time::Instant::now();
-		let result = self.query_data_proof(transaction_index, at).await;
+		let result = self.query_data_proof(transaction_index, at, None).await;
 		let elapsed = start.elapsed();
 
-		result.map(|r| (r, elapsed.as_micros()))
+		result.map(|r| (r.into_data(), elapsed.as_micros()))
+	}
+
+	async fn query_data_proof_by_tx_hash_metrics(
+		&self,
+		tx_hash: HashOf<Block>,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(ProofResponse, u128)> {
+		let start = std::time::Instant::now();
+		let result = self.query_data_proof_by_tx_hash(tx_hash, at, None).await;
+		let elapsed = start.elapsed();
+
+		result.map(|r| (r.into_data(), elapsed.as_micros()))
+	}
+
+	async fn query_data_proof_by_data_hash_metrics(
+		&self,
+		data_hash: H256,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(ProofResponse, u128)> {
+		let start = std::time::Instant::now();
+		let result = self
+			.query_data_proof_by_data_hash(data_hash, at, None)
+			.await;
+		let elapsed = start.elapsed();
+
+		result.map(|r| (r.into_data(), elapsed.as_micros()))
+	}
+
+	async fn query_data_proofs_metrics(
+		&self,
+		transaction_indices: TransactionIndices,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(DataMultiProof, u128)> {
+		let start = std::time::Instant::now();
+		let result = self.query_data_proofs(transaction_indices, at, None).await;
+		let elapsed = start.elapsed();
+
+		result.map(|r| (r.into_data(), elapsed.as_micros()))
+	}
+
+	async fn query_data_non_inclusion_proof_metrics(
+		&self,
+		leaf_hash: H256,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(DataMultiProof, u128)> {
+		let start = std::time::Instant::now();
+		let result = self
+			.query_data_non_inclusion_proof(leaf_hash, at, None)
+			.await;
+		let elapsed = start.elapsed();
+
+		result.map(|r| (r.into_data(), elapsed.as_micros()))
+	}
+
+	async fn query_app_data_metrics(
+		&self,
+		app_id: AppId,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(Vec<(u32, GRow)>, u128)> {
+		let start = std::time::Instant::now();
+		let result = self.query_app_data(app_id, at, None).await;
+		let elapsed = start.elapsed();
+
+		result.map(|r| (r.into_data(), elapsed.as_micros()))
+	}
+
+	async fn query_blobs_metrics(
+		&self,
+		app_id: AppId,
+		namespace: Option<Namespace>,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<(Vec<Blob>, u128)> {
+		let start = std::time::Instant::now();
+		let result = self.query_blobs(app_id, namespace, at, None).await;
+		let elapsed = start.elapsed();
+
+		result.map(|r| (r.into_data(), elapsed.as_micros()))
 	}
 }
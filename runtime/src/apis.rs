@@ -1,21 +1,27 @@
-use super::kate::{Error as RTKateError, GDataProof, GRow};
+use super::kate::{Error as RTKateError, GDataProof, GMultiProofs, GRow};
 use crate::{
 	constants, mmr, version::VERSION, AccountId, AuthorityDiscovery, Babe, Block, BlockNumber,
 	EpochDuration, Executive, Grandpa, Historical, Index, InherentDataExt, Mmr, NominationPools,
 	OpaqueMetadata, Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, Staking, System,
 	TransactionPayment, LOG_TARGET,
 };
-use avail_base::{HeaderExtensionBuilderData, ProvidePostInherent};
+use avail_base::{
+	header_extension::{DataMultiProof, Namespace, SubmittedData},
+	HeaderExtensionBuilderData, ProvidePostInherent,
+};
 use avail_core::{
 	currency::Balance,
 	data_proof::{DataProof, ProofResponse, SubTrie},
 	header::HeaderExtension,
-	OpaqueExtrinsic,
+	traits::GetAppId,
+	AppId, OpaqueExtrinsic,
 };
+use da_control::{AppKeyInfo, CheckAppId, PendingBlockLength};
 
 use frame_system::limits::BlockLength;
 
 use frame_support::{
+	dispatch::{DispatchInfo, GetDispatchInfo},
 	genesis_builder_helper::{build_config, create_default_config},
 	traits::KeyOwnerProofSystem,
 	weights::Weight,
@@ -41,6 +47,10 @@ decl_runtime_apis! {
 	#[api_version(2)]
 	pub trait DataAvailApi {
 		fn block_length() -> BlockLength;
+		#[api_version(3)]
+		fn app_info(app_id: AppId) -> Option<(Vec<u8>, AppKeyInfo<AccountId>)>;
+		#[api_version(4)]
+		fn pending_block_length() -> Option<PendingBlockLength<BlockNumber>>;
 	}
 
 	pub trait ExtensionBuilder {
@@ -64,8 +74,20 @@ decl_runtime_apis! {
 
 	pub trait KateApi {
 		fn data_proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, tx_idx: u32) -> Option<ProofResponse>;
+		#[api_version(4)]
+		fn data_submission_tx_idx(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, data_hash: H256) -> Option<u32>;
+		#[api_version(5)]
+		fn data_proofs(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, tx_indices: Vec<u32>) -> Option<DataMultiProof>;
+		#[api_version(6)]
+		fn data_non_inclusion_proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, leaf_hash: H256) -> Option<DataMultiProof>;
+		#[api_version(7)]
+		fn data_submissions(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, app_id: AppId, namespace: Option<Namespace>) -> Vec<SubmittedData>;
 		fn rows(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, rows: Vec<u32>) -> Result<Vec<GRow>, RTKateError >;
 		fn proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, cells: Vec<(u32,u32)> ) -> Result<Vec<GDataProof>, RTKateError>;
+		#[api_version(2)]
+		fn app_data(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, app_id: AppId) -> Result<Vec<(u32, GRow)>, RTKateError>;
+		#[api_version(3)]
+		fn multiproof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, cells: Vec<(u32,u32)>) -> Result<GMultiProofs, RTKateError>;
 	}
 }
 
@@ -75,6 +97,16 @@ pub(crate) const fn runtime_api_versions() -> Cow<'static, [([u8; 8], u32)]> {
 
 pub static NATIVE_VERSION: &RuntimeVersion = &VERSION;
 
+/// Returns the dispatch info of a signed data submission with the fee modifier of its
+/// application, as charged by `da_control::ChargeAppTransactionPayment`, if the application has one.
+fn app_dispatch_info(uxt: &<Block as BlockT>::Extrinsic) -> Option<DispatchInfo> {
+	if uxt.is_signed() != Some(true) {
+		return None;
+	}
+	let info = uxt.get_dispatch_info();
+	CheckAppId::<Runtime>::from(uxt.app_id()).app_dispatch_info(&uxt.function, &info)
+}
+
 impl_runtime_apis! {
 	impl sp_api::Core<Block> for Runtime {
 		fn version() -> RuntimeVersion {
@@ -242,10 +274,20 @@ impl_runtime_apis! {
 		Balance,
 	> for Runtime {
 		fn query_info(uxt: <Block as BlockT>::Extrinsic, len: u32) -> RuntimeDispatchInfo<Balance> {
-			TransactionPayment::query_info(uxt, len)
+			match app_dispatch_info(&uxt) {
+				Some(info) => RuntimeDispatchInfo {
+					weight: info.weight,
+					class: info.class,
+					partial_fee: TransactionPayment::compute_fee(len, &info, 0),
+				},
+				None => TransactionPayment::query_info(uxt, len),
+			}
 		}
 		fn query_fee_details(uxt: <Block as BlockT>::Extrinsic, len: u32) -> FeeDetails<Balance> {
-			TransactionPayment::query_fee_details(uxt, len)
+			match app_dispatch_info(&uxt) {
+				Some(info) => TransactionPayment::compute_fee_details(len, &info, 0),
+				None => TransactionPayment::query_fee_details(uxt, len),
+			}
 		}
 
 		fn query_weight_to_fee(weight: Weight) -> Balance {
@@ -344,10 +386,19 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(4)]
 	impl crate::apis::DataAvailApi<Block> for Runtime {
 		fn block_length() -> frame_system::limits::BlockLength {
 			frame_system::Pallet::<Runtime>::block_length()
 		}
+
+		fn app_info(app_id: AppId) -> Option<(Vec<u8>, AppKeyInfo<AccountId>)> {
+			da_control::Pallet::<Runtime>::app_info(app_id).map(|(key, info)| (key.into_inner(), info))
+		}
+
+		fn pending_block_length() -> Option<PendingBlockLength<BlockNumber>> {
+			da_control::Pallet::<Runtime>::pending_block_length()
+		}
 	}
 
 	#[api_version(4)]
@@ -400,6 +451,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(7)]
 	impl crate::apis::KateApi<Block> for Runtime {
 		fn data_proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, tx_idx: u32) -> Option<ProofResponse> {
 			let data = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics);
@@ -430,6 +482,46 @@ impl_runtime_apis! {
 			Some(proof)
 		}
 
+		fn data_submission_tx_idx(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, data_hash: H256) -> Option<u32> {
+			let data = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics);
+			let tx_idx = data.data_submission_tx_idx(data_hash);
+			log::trace!(
+				target: LOG_TARGET,
+				"KateApi::data_submission_tx_idx: data_hash={data_hash:?} tx_idx={tx_idx:?}");
+
+			tx_idx
+		}
+
+		fn data_proofs(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, tx_indices: Vec<u32>) -> Option<DataMultiProof> {
+			let data = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics);
+			let proof = data.data_multi_proof(&tx_indices);
+			log::trace!(
+				target: LOG_TARGET,
+				"KateApi::data_proofs: tx_indices={tx_indices:?} proof={proof:#?}");
+
+			proof
+		}
+
+		fn data_non_inclusion_proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, leaf_hash: H256) -> Option<DataMultiProof> {
+			let data = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics);
+			let proof = data.data_non_inclusion_proof(leaf_hash);
+			log::trace!(
+				target: LOG_TARGET,
+				"KateApi::data_non_inclusion_proof: leaf_hash={leaf_hash:?} proof={proof:#?}");
+
+			proof
+		}
+
+		fn data_submissions(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, app_id: AppId, namespace: Option<Namespace>) -> Vec<SubmittedData> {
+			let data = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics);
+			let submissions = data.data_submissions_of(app_id, namespace).cloned().collect::<Vec<_>>();
+			log::trace!(
+				target: LOG_TARGET,
+				"KateApi::data_submissions: app_id={app_id:?} namespace={namespace:?} submissions={}", submissions.len());
+
+			submissions
+		}
+
 		fn rows(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, rows: Vec<u32>) -> Result<Vec<GRow>, RTKateError> {
 			let app_extrinsics = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics).to_app_extrinsics();
 			let grid_rows = super::kate::grid::<Runtime>(app_extrinsics, block_len, rows)?;
@@ -443,6 +535,20 @@ impl_runtime_apis! {
 			log::trace!(target: LOG_TARGET, "KateApi::proof: data_proofs={data_proofs:#?}");
 			Ok(data_proofs)
 		}
+
+		fn multiproof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, cells: Vec<(u32,u32)>) -> Result<GMultiProofs, RTKateError> {
+			let app_extrinsics = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics).to_app_extrinsics();
+			let multiproofs = super::kate::multiproof::<Runtime>(app_extrinsics, block_len, cells)?;
+			log::trace!(target: LOG_TARGET, "KateApi::multiproof: multiproofs={multiproofs:#?}");
+			Ok(multiproofs)
+		}
+
+		fn app_data(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, app_id: AppId) -> Result<Vec<(u32, GRow)>, RTKateError> {
+			let app_extrinsics = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics).to_app_extrinsics();
+			let app_rows = super::kate::app_data::<Runtime>(app_extrinsics, block_len, app_id)?;
+			log::trace!(target: LOG_TARGET, "KateApi::app_data: app_id={app_id:?} app_rows={app_rows:#?}");
+			Ok(app_rows)
+		}
 	}
 
 	impl avail_base::PostInherentsProvider<Block> for Runtime {
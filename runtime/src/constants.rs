@@ -353,9 +353,23 @@ pub mod da {
 		pub const MaxBlockRows: BlockLengthRows = BlockLengthRows(1024);
 		pub const MinBlockCols: BlockLengthColumns = BlockLengthColumns(64);
 		pub const MaxBlockCols: BlockLengthColumns = BlockLengthColumns(1024);
+		pub const AppKeyDeposit: Balance = 10 * AVAIL;
+		pub const AppKeyByteDeposit: Balance = currency::deposit(0, 1);
 	}
+
+	#[cfg(not(feature = "fast-runtime"))]
+	parameter_types! {
+		pub const BlockLengthEnactmentDelay: BlockNumber = 1 * super::time::HOURS;
+	}
+
+	#[cfg(feature = "fast-runtime")]
+	parameter_types! {
+		pub const BlockLengthEnactmentDelay: BlockNumber = 0;
+	}
+
 	pub type MaxAppKeyLength = ConstU32<64>;
 	pub type MaxAppDataLength = ConstU32<524_288>; // 512 Kb
+	pub type MaxUtilisationWindow = ConstU32<256>;
 }
 
 /// Macro to set a value (e.g. when using the `parameter_types` macro) to either a production value
@@ -1,9 +1,14 @@
 use super::*;
 use crate::{Runtime, SignedExtra, UncheckedExtrinsic};
 
-use avail_base::HeaderExtensionBuilderData;
+use avail_base::{
+	header_extension::{
+		verify_data_multi_proof, verify_data_non_inclusion_proof, verify_multi_proof,
+	},
+	HeaderExtensionBuilderData,
+};
 use avail_core::data_proof::{BoundedData, Message, TxDataRoots};
-use da_control::{AppDataFor, Call as DaCall, CheckAppId};
+use da_control::{AppDataFor, Call as DaCall, ChargeAppTransactionPayment, CompressionCodec};
 use frame_system::{
 	CheckEra, CheckGenesis, CheckNonZeroSender, CheckNonce, CheckSpecVersion, CheckTxVersion,
 	CheckWeight,
@@ -17,7 +22,6 @@ use binary_merkle_tree::{verify_proof, Leaf, MerkleProof};
 use codec::{Compact, Encode};
 use derive_more::Constructor;
 use hex_literal::hex;
-use pallet_transaction_payment::ChargeTransactionPayment;
 use sp_core::H256;
 use sp_keyring::AccountKeyring::{Alice, Bob};
 use sp_runtime::traits::Keccak256;
@@ -110,8 +114,7 @@ fn extra() -> SignedExtra {
 		CheckEra::<Runtime>::from(Era::Mortal(32, 2)),
 		CheckNonce::<Runtime>::from(0),
 		CheckWeight::<Runtime>::new(),
-		ChargeTransactionPayment::<Runtime>::from(0),
-		CheckAppId::<Runtime>::from(AppId(1)),
+		ChargeAppTransactionPayment::<Runtime>::from(0, AppId(1)),
 	)
 }
 fn additional_signed() -> <SignedExtra as SignedExtension>::AdditionalSigned {
@@ -120,7 +123,7 @@ fn additional_signed() -> <SignedExtra as SignedExtension>::AdditionalSigned {
 	let genesis = H256::default();
 	let era = H256::repeat_byte(1);
 
-	((), spec_ver, tx_ver, genesis, era, (), (), (), ())
+	((), spec_ver, tx_ver, genesis, era, (), (), ())
 }
 
 fn signed_extrinsic(function: RuntimeCall) -> Vec<u8> {
@@ -142,6 +145,18 @@ fn submit_data(data: Vec<u8>) -> Vec<u8> {
 	signed_extrinsic(function)
 }
 
+fn submit_namespaced_data(data: Vec<u8>, namespace: u32) -> Vec<u8> {
+	let data = AppDataFor::<Runtime>::truncate_from(data);
+	let function = DaCall::submit_data_with_options {
+		data,
+		codec: CompressionCodec::None,
+		namespace: Some(namespace),
+	}
+	.into();
+
+	signed_extrinsic(function)
+}
+
 fn transfer_keep_alive() -> Vec<u8> {
 	let bob = Bob.to_account_id();
 	let amount = 1 * AVAIL;
@@ -557,6 +572,146 @@ mod data_root {
 		assert_eq!(da_proof.number_of_leaves, 4);
 	}
 
+	#[test]
+	fn test_data_multi_proof_with_skipped_tx() {
+		let extrinsics: Vec<Vec<u8>> = vec![
+			submit_data("0".into()),
+			submit_data("".into()),
+			submit_data("1".into()),
+			submit_data("2".into()),
+		];
+		let data = HeaderExtensionBuilderData::from_raw_extrinsics::<Runtime>(0, &extrinsics);
+		let exp_submitted_root = H256(hex!(
+			"877f9ed6aa67f160e9b9b7794bb851998d15b65d11bab3efc6ff444339a3d750"
+		));
+
+		// Leaves 0 and 2 do not share any sibling.
+		let multi_proof = data.data_multi_proof(&[3, 0]).unwrap();
+		assert_eq!(multi_proof.data_root, data.data_root());
+		assert_eq!(multi_proof.proof.root, exp_submitted_root);
+		assert_eq!(multi_proof.proof.number_of_leaves, 4);
+		assert_eq!(multi_proof.proof.leaf_indices, vec![0, 2]);
+		assert_eq!(
+			multi_proof.proof.proof,
+			vec![
+				H256(hex!(
+					"4aeff0db81e3146828378be230d377356e57b6d599286b4b517dbf8941b3e1b2"
+				)),
+				H256(hex!(
+					"290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563"
+				)),
+			]
+		);
+		assert!(verify_data_multi_proof(data.data_root(), &multi_proof));
+
+		// Leaves 0 and 1 only need the root of the right subtree.
+		let multi_proof = data.data_multi_proof(&[0, 2]).unwrap();
+		assert_eq!(
+			multi_proof.proof.proof,
+			vec![H256(hex!(
+				"3c86bde3a90d18efbcf23e27e9b6714012aa055263fe903a72333aa9caa37f1b"
+			))]
+		);
+		assert!(verify_data_multi_proof(data.data_root(), &multi_proof));
+
+		// Every leaf needs no sibling at all.
+		let multi_proof = data.submitted_multi_proof_of(&[0, 1, 2, 3]).unwrap();
+		assert!(multi_proof.proof.is_empty());
+		assert!(verify_multi_proof(&multi_proof));
+
+		// Tampered proofs and non-submission transactions are rejected.
+		let mut multi_proof = data.data_multi_proof(&[0, 2]).unwrap();
+		multi_proof.proof.leaves[1] = H256::repeat_byte(1);
+		assert!(!verify_data_multi_proof(data.data_root(), &multi_proof));
+		assert!(data.data_multi_proof(&[1]).is_none());
+		assert!(data.data_multi_proof(&[4]).is_none());
+	}
+
+	#[test]
+	fn test_data_non_inclusion_proof() {
+		let extrinsics: Vec<Vec<u8>> = vec![
+			submit_data("0".into()),
+			submit_data("1".into()),
+			submit_data("2".into()),
+		];
+		let data = HeaderExtensionBuilderData::from_raw_extrinsics::<Runtime>(0, &extrinsics);
+		let leaf_hash_of = |data: &[u8]| H256(keccak_256(&keccak_256(data)));
+
+		let absent = leaf_hash_of("3".as_bytes());
+		let proof = data.data_non_inclusion_proof(absent).unwrap();
+		assert_eq!(proof.proof.leaves.len(), 4);
+		assert!(proof.proof.proof.is_empty());
+		assert!(verify_data_non_inclusion_proof(
+			data.data_root(),
+			absent,
+			&proof
+		));
+
+		// Included leaves cannot be proven absent.
+		let included = leaf_hash_of("1".as_bytes());
+		assert!(data.data_non_inclusion_proof(included).is_none());
+		assert!(!verify_data_non_inclusion_proof(
+			data.data_root(),
+			included,
+			&proof
+		));
+
+		// Proofs missing some leaf are rejected.
+		let mut partial = proof.clone();
+		partial.proof = data.submitted_multi_proof_of(&[0, 2, 3]).unwrap();
+		assert!(verify_data_multi_proof(data.data_root(), &partial));
+		assert!(!verify_data_non_inclusion_proof(
+			data.data_root(),
+			absent,
+			&partial
+		));
+
+		// Blocks without data submissions have no leaves at all.
+		let data = HeaderExtensionBuilderData::from_raw_extrinsics::<Runtime>(0, &[]);
+		let proof = data.data_non_inclusion_proof(absent).unwrap();
+		assert_eq!(proof.proof.number_of_leaves, 0);
+		assert!(verify_data_non_inclusion_proof(
+			data.data_root(),
+			absent,
+			&proof
+		));
+	}
+
+	#[test]
+	fn test_namespaced_data_leaves() {
+		let extrinsics: Vec<Vec<u8>> = vec![
+			submit_data("0".into()),
+			submit_namespaced_data("1".into(), 7),
+			submit_namespaced_data("2".into(), 8),
+			submit_namespaced_data("3".into(), 7),
+		];
+		let data = HeaderExtensionBuilderData::from_raw_extrinsics::<Runtime>(0, &extrinsics);
+		let proof = data.submitted_multi_proof_of(&[0, 1]).unwrap();
+
+		// Leaves do not depend on the namespace of their submission.
+		assert_eq!(proof.leaves[0], H256(keccak_256("0".as_bytes())));
+		assert_eq!(proof.leaves[1], H256(keccak_256("1".as_bytes())));
+		assert!(verify_data_multi_proof(
+			data.data_root(),
+			&data.data_multi_proof(&[0, 1]).unwrap()
+		));
+
+		// Namespaced data is covered by non-inclusion proofs as well.
+		let included = H256(keccak_256(&keccak_256("3".as_bytes())));
+		assert!(data.data_non_inclusion_proof(included).is_none());
+
+		let tx_indices = |namespace| {
+			data.data_submissions_of(AppId(0), namespace)
+				.map(|s| s.tx_index)
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(tx_indices(None), vec![0, 1, 2, 3]);
+		assert_eq!(tx_indices(Some(7)), vec![1, 3]);
+		assert_eq!(tx_indices(Some(8)), vec![2]);
+		assert!(tx_indices(Some(9)).is_empty());
+		assert_eq!(data.data_submissions_of(AppId(1), None).count(), 0);
+	}
+
 	#[test]
 	fn test_message_encoding() {
 		let expected_encoded_message = hex!("00000000000000000000000000000000000000000000000000000000000000200200000000000000000000000000000000000000000000000000000000000000681257bed628425a28b469114dc21a7c30205cfd00000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000e00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000de0b6b3a7640000").to_vec();
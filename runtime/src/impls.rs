@@ -1,6 +1,6 @@
 use crate::{
-	constants, prod_or_fast, voter_bags, weights, AccountId, AccountIndex, Babe, Balances, Block,
-	BlockNumber, ElectionProviderMultiPhase, Everything, Hash, Header, Historical, ImOnline,
+	constants, prod_or_fast, voter_bags, weights, AccountId, AccountIndex, Assets, Babe, Balances,
+	Block, BlockNumber, ElectionProviderMultiPhase, Everything, Hash, Header, Historical, ImOnline,
 	ImOnlineId, Index, Indices, Moment, NominationPools, Offences, OriginCaller, PalletInfo,
 	Preimage, ReserveIdentifier, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason,
 	RuntimeHoldReason, RuntimeOrigin, RuntimeVersion, Session, SessionKeys, Signature,
@@ -26,8 +26,9 @@ use frame_support::{
 		tokens::{
 			imbalance::ResolveTo, pay::PayFromAccount, Imbalance, UnityAssetBalanceConversion,
 		},
-		ConstU32, Contains, Currency, EitherOf, EitherOfDiverse, EqualPrivilegeOnly, InsideBoth,
-		InstanceFilter, LinearStoragePrice, OnUnbalanced,
+		AsEnsureOriginWithArg, ConstU32, Contains, Currency, EitherOf, EitherOfDiverse,
+		EqualPrivilegeOnly, InsideBoth, InstanceFilter, LinearStoragePrice, NeverEnsureOrigin,
+		OnUnbalanced,
 	},
 	weights::{constants::RocksDbWeight, ConstantMultiplier},
 	PalletId,
@@ -75,9 +76,14 @@ impl pallet_vector::Config for Runtime {
 	type WeightInfo = weights::pallet_vector::WeightInfo<Runtime>;
 	type TimeProvider = pallet_timestamp::Pallet<Runtime>;
 	type Currency = Balances;
+	type Assets = Assets;
 	type MessageMappingStorageIndex = ConstU64<1>;
 	type PalletId = BridgePalletId;
 	type AvailDomain = ConstU32<1>;
+	type MessageHandler = ();
+	// 64 sync committee periods of 8192 slots, about 72 days.
+	type RetainedSlots = ConstU64<{ 8192 * 64 }>;
+	type MaxPrunedPerBlock = ConstU32<32>;
 }
 
 parameter_types! {
@@ -118,11 +124,16 @@ impl pallet_identity::Config for Runtime {
 }
 
 impl da_control::Config for Runtime {
+	type AppKeyByteDeposit = constants::da::AppKeyByteDeposit;
+	type AppKeyDeposit = constants::da::AppKeyDeposit;
 	type BlockLenProposalId = u32;
+	type BlockLengthEnactmentDelay = constants::da::BlockLengthEnactmentDelay;
+	type Currency = Balances;
 	type MaxAppDataLength = constants::da::MaxAppDataLength;
 	type MaxAppKeyLength = constants::da::MaxAppKeyLength;
 	type MaxBlockCols = constants::da::MaxBlockCols;
 	type MaxBlockRows = constants::da::MaxBlockRows;
+	type MaxUtilisationWindow = constants::da::MaxUtilisationWindow;
 	type MinBlockCols = constants::da::MinBlockCols;
 	type MinBlockRows = constants::da::MinBlockRows;
 	type RuntimeEvent = RuntimeEvent;
@@ -790,6 +801,39 @@ impl Contains<RuntimeCallNameOf<Runtime>> for TxPauseWhitelistedCalls {
 	}
 }
 
+parameter_types! {
+	pub const AssetDeposit: Balance = 100 * AVAIL;
+	pub const AssetAccountDeposit: Balance = constants::currency::deposit(1, 16);
+	pub const ApprovalDeposit: Balance = AVAIL;
+	pub const AssetsStringLimit: u32 = 50;
+	pub const MetadataDepositBase: Balance = constants::currency::deposit(1, 68);
+	pub const MetadataDepositPerByte: Balance = constants::currency::deposit(0, 1);
+}
+
+/// Assets which Ethereum assets are bridged to by `Vector`, only created by governance.
+impl pallet_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = u32;
+	type AssetIdParameter = codec::Compact<u32>;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<NeverEnsureOrigin<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = AssetsStringLimit;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+	type RemoveItemsLimit = ConstU32<1000>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
 impl pallet_tx_pause::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -907,8 +951,7 @@ where
 			frame_system::CheckEra::<Runtime>::from(era),
 			frame_system::CheckNonce::<Runtime>::from(nonce),
 			frame_system::CheckWeight::<Runtime>::new(),
-			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
-			da_control::CheckAppId::<Runtime>::from(AppId(0)),
+			da_control::ChargeAppTransactionPayment::<Runtime>::from(tip, AppId(0)),
 		);
 		let raw_payload = SignedPayload::new(call, extra)
 			.map_err(|e| {
@@ -0,0 +1,118 @@
+//! Node-side cache of the grids used by the `HostedKate` functions.
+//!
+//! Light clients usually sample the same (recent) blocks from many connections, so the extended
+//! evaluation grid and its polynomial grid are kept between Kate RPC calls instead of being
+//! rebuilt on each one.
+//! Host functions only receive the block's inputs, so entries are keyed by the hash of those
+//! inputs (app extrinsics, block length and seed), which uniquely identifies each block.
+#![cfg(feature = "std")]
+
+use super::Error;
+use avail_base::metrics::avail::KateRpcMetrics;
+use avail_core::AppExtrinsic;
+use codec::Encode;
+use core::num::NonZeroU16;
+use frame_system::{limits::BlockLength, native::hosted_header_builder::MIN_WIDTH};
+use kate::{
+	gridgen::{EvaluationGrid as EGrid, PolynomialGrid},
+	Seed,
+};
+use lru::LruCache;
+use sp_core::{hashing::blake2_256, H256};
+use sp_runtime::SaturatedConversion as _;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+
+type Cache = Mutex<LruCache<H256, Arc<CachedGrid>>>;
+
+static GRID_CACHE: OnceLock<Cache> = OnceLock::new();
+
+/// Default number of blocks kept in the cache.
+pub const DEFAULT_CACHE_SIZE: usize = 16;
+
+/// Enables the cache, keeping the grids of up to `capacity` blocks.
+///
+/// Only the first call has effect. If it is never called, or `capacity` is zero, the cache is
+/// disabled and grids are rebuilt on each call.
+pub fn init(capacity: usize) {
+	if capacity == 0 {
+		return;
+	}
+	let _ = GRID_CACHE.set(Mutex::new(LruCache::new(capacity)));
+}
+
+/// Grids of a single block.
+pub struct CachedGrid {
+	/// Evaluation grid built from the block's app extrinsics.
+	pub grid: EGrid,
+	/// `grid` with its columns extended.
+	pub extended: EGrid,
+	/// Polynomial grid of `extended`, built on first use.
+	poly: OnceLock<PolynomialGrid>,
+}
+
+impl CachedGrid {
+	fn build(
+		submitted: Vec<AppExtrinsic>,
+		block_length: &BlockLength,
+		seed: Seed,
+	) -> Result<Self, Error> {
+		let (max_width, max_height) = to_width_height(block_length);
+		let grid = EGrid::from_extrinsics(submitted, MIN_WIDTH, max_width, max_height, seed)?;
+		let extended = grid
+			.extend_columns(NonZeroU16::new(2).expect("2>0"))
+			.map_err(|_| Error::ColumnExtension)?;
+
+		Ok(Self {
+			grid,
+			extended,
+			poly: OnceLock::new(),
+		})
+	}
+
+	/// Returns the polynomial grid of the extended grid, building it if needed.
+	pub fn poly(&self) -> Result<&PolynomialGrid, Error> {
+		if let Some(poly) = self.poly.get() {
+			return Ok(poly);
+		}
+
+		let poly = self.extended.make_polynomial_grid()?;
+		Ok(self.poly.get_or_init(|| poly))
+	}
+}
+
+/// Returns the grids of the block defined by `submitted`, `block_length` and `seed`, from the
+/// cache if they were already built.
+pub fn get_or_build(
+	submitted: Vec<AppExtrinsic>,
+	block_length: &BlockLength,
+	seed: Seed,
+) -> Result<Arc<CachedGrid>, Error> {
+	let Some(cache) = GRID_CACHE.get() else {
+		return CachedGrid::build(submitted, block_length, seed).map(Arc::new);
+	};
+
+	let key = H256((&submitted, block_length, &seed).using_encoded(blake2_256));
+	if let Some(cached) = lock(cache).get(&key) {
+		KateRpcMetrics::observe_grid_cache_hit();
+		return Ok(Arc::clone(cached));
+	}
+	KateRpcMetrics::observe_grid_cache_miss();
+
+	// The lock is not held while building, so concurrent misses on the same block may build it
+	// more than once.
+	let cached = Arc::new(CachedGrid::build(submitted, block_length, seed)?);
+	lock(cache).put(key, Arc::clone(&cached));
+
+	Ok(cached)
+}
+
+fn lock(cache: &Cache) -> std::sync::MutexGuard<'_, LruCache<H256, Arc<CachedGrid>>> {
+	cache.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn to_width_height(block_len: &BlockLength) -> (usize, usize) {
+	// even if we run on a u16 target this is fine
+	let width = block_len.cols.0.saturated_into();
+	let height = block_len.rows.0.saturated_into();
+	(width, height)
+}
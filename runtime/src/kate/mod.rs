@@ -1,8 +1,9 @@
+pub mod grid_cache;
 pub mod native;
 pub mod runtime;
 
 // Reexport
-pub use runtime::{grid, proof};
+pub use runtime::{app_data, grid, multiproof, proof};
 
 use codec::{Decode, Encode};
 use core::num::TryFromIntError;
@@ -20,6 +21,7 @@ use kate::{com::Error as KateError, gridgen::AppRowError as KateAppRowError};
 pub type GRawScalar = U256;
 pub type GRow = Vec<GRawScalar>;
 pub type GDataProof = (GRawScalar, GProof);
+pub type GMultiProof = (Vec<GRawScalar>, GProof);
 
 /// # NOTE
 /// `Serde` requires a custom implementation for `GProof` due to the array size (greater than `[T;32]`).
@@ -48,7 +50,32 @@ impl TryFrom<Vec<u8>> for GProof {
 	}
 }
 
-#[derive(Error, Encode, Decode, TypeInfo, PassByCodec, Debug)]
+/// Cells of the extended grid covered by one multiproof: rows `start_y..end_y` and columns
+/// `start_x..end_x`.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct GCellBlock {
+	pub start_x: u32,
+	pub start_y: u32,
+	pub end_x: u32,
+	pub end_y: u32,
+}
+
+/// Multiproofs of some cells of the multiproof grid.
+///
+/// The multiproof grid partitions the extended grid into `rows * cols` cell blocks, and each
+/// requested cell `(row, col)` of it is proven by a single proof over its whole cell block.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct GMultiProofs {
+	pub rows: u16,
+	pub cols: u16,
+	pub proofs: Vec<(GMultiProof, GCellBlock)>,
+}
+
+#[derive(Error, Encode, Decode, TypeInfo, PassByCodec, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum Error {
 	#[error("Invalid integer conversion")]
 	TryFromInt,
@@ -68,6 +95,8 @@ pub enum Error {
 	Proof,
 	#[error("Failed to extend columns")]
 	ColumnExtension,
+	#[error("Grid of {rows}x{cols} cannot be partitioned into the multiproof grid")]
+	InvalidMultiproofGrid { rows: u32, cols: u32 },
 }
 
 impl From<TryFromIntError> for Error {
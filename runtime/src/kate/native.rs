@@ -1,25 +1,33 @@
-use super::{Error, GDataProof, GProof, GRawScalar, GRow};
+use super::{Error, GCellBlock, GDataProof, GMultiProof, GMultiProofs, GProof, GRawScalar, GRow};
 use avail_core::{AppExtrinsic, AppId, BlockLengthColumns, BlockLengthRows};
-use core::num::NonZeroU16;
-use frame_system::{limits::BlockLength, native::hosted_header_builder::MIN_WIDTH};
+use frame_system::limits::BlockLength;
 use kate::Seed;
 #[cfg(feature = "std")]
 use kate::{
 	com::Cell,
 	couscous::multiproof_params,
-	gridgen::{AsBytes as _, EvaluationGrid as EGrid},
+	gridgen::{multiproof_dims, AsBytes as _},
 	pmp::m1_blst::M1NoPrecomp,
 };
-use sp_runtime::SaturatedConversion as _;
+#[cfg(feature = "std")]
+use kate_recovery::matrix::Dimensions;
 use sp_runtime_interface::runtime_interface;
 use sp_std::vec::Vec;
 
+#[cfg(feature = "std")]
+use super::grid_cache;
+
 #[cfg(feature = "std")]
 static SRS: std::sync::OnceLock<M1NoPrecomp> = std::sync::OnceLock::new();
 
 #[cfg(feature = "std")]
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
+/// Dimensions of the multiproof grid, which light clients expect the extended grid to be
+/// partitioned into.
+#[cfg(feature = "std")]
+const MULTIPROOF_GRID: (u16, u16) = (16, 64);
+
 /// Hosted function to build the header using `kate` commitments.
 #[runtime_interface]
 pub trait HostedKate {
@@ -29,15 +37,13 @@ pub trait HostedKate {
 		seed: Seed,
 		selected_rows: Vec<u32>,
 	) -> Result<Vec<GRow>, Error> {
-		let (max_width, max_height) = to_width_height(&block_length);
 		let selected_rows = selected_rows
 			.into_par_iter()
 			.map(usize::try_from)
 			.collect::<Result<Vec<_>, _>>()?;
 
-		let grid = EGrid::from_extrinsics(submitted, MIN_WIDTH, max_width, max_height, seed)?
-			.extend_columns(NonZeroU16::new(2).expect("2>0"))
-			.map_err(|_| Error::ColumnExtension)?;
+		let cached = grid_cache::get_or_build(submitted, &block_length, seed)?;
+		let grid = &cached.extended;
 		let rows = selected_rows
 			.into_par_iter()
 			.map(|row_idx| {
@@ -59,12 +65,9 @@ pub trait HostedKate {
 		cells: Vec<(u32, u32)>,
 	) -> Result<Vec<GDataProof>, Error> {
 		let srs = SRS.get_or_init(multiproof_params);
-		let (max_width, max_height) = to_width_height(&block_len);
-		let grid = EGrid::from_extrinsics(extrinsics, MIN_WIDTH, max_width, max_height, seed)?
-			.extend_columns(NonZeroU16::new(2).expect("2>0"))
-			.map_err(|_| Error::ColumnExtension)?;
-
-		let poly = grid.make_polynomial_grid()?;
+		let cached = grid_cache::get_or_build(extrinsics, &block_len, seed)?;
+		let grid = &cached.extended;
+		let poly = cached.poly()?;
 
 		let proofs = cells
 			.into_par_iter()
@@ -90,19 +93,79 @@ pub trait HostedKate {
 		Ok(proofs)
 	}
 
+	fn multiproof(
+		extrinsics: Vec<AppExtrinsic>,
+		block_len: BlockLength,
+		seed: Seed,
+		cells: Vec<(u32, u32)>,
+	) -> Result<GMultiProofs, Error> {
+		let srs = SRS.get_or_init(multiproof_params);
+		let cached = grid_cache::get_or_build(extrinsics, &block_len, seed)?;
+		let grid = &cached.extended;
+		let poly = cached.poly()?;
+
+		let (target_rows, target_cols) = MULTIPROOF_GRID;
+		let target_dims =
+			Dimensions::new(target_rows, target_cols).expect("Multiproof grid is not empty");
+		let dims = grid.dims();
+		let mp_dims =
+			multiproof_dims(dims, target_dims).ok_or_else(|| Error::InvalidMultiproofGrid {
+				rows: dims.height() as u32,
+				cols: dims.width() as u32,
+			})?;
+
+		let proofs = cells
+			.into_par_iter()
+			.map(|(row, col)| -> Result<(GMultiProof, GCellBlock), Error> {
+				if row as usize >= mp_dims.height() || col as usize >= mp_dims.width() {
+					return Err(Error::MissingCell { row, col });
+				}
+
+				let cell = Cell::new(BlockLengthRows(row), BlockLengthColumns(col));
+				let mp = poly
+					.multiproof(srs, &cell, grid, target_dims)
+					.map_err(|_| Error::Proof)?;
+
+				let data = mp
+					.evals
+					.into_iter()
+					.flatten()
+					.map(|scalar| scalar.to_bytes().map(GRawScalar::from))
+					.collect::<Result<Vec<_>, _>>()
+					.map_err(|_| Error::InvalidScalarAtRow(row))?;
+				let proof = mp.proof.to_bytes().map(GProof).map_err(|_| Error::Proof)?;
+				let block = GCellBlock {
+					start_x: mp.block.start_x.try_into()?,
+					start_y: mp.block.start_y.try_into()?,
+					end_x: mp.block.end_x.try_into()?,
+					end_y: mp.block.end_y.try_into()?,
+				};
+
+				Ok(((data, proof), block))
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Ok(GMultiProofs {
+			rows: mp_dims.height().try_into()?,
+			cols: mp_dims.width().try_into()?,
+			proofs,
+		})
+	}
+
 	fn app_data(
 		submitted: Vec<AppExtrinsic>,
 		block_length: BlockLength,
 		seed: Seed,
 		app_id: u32,
 	) -> Result<Vec<Option<GRow>>, Error> {
-		let (max_width, max_height) = to_width_height(&block_length);
-		let grid = EGrid::from_extrinsics(submitted, MIN_WIDTH, max_width, max_height, seed)?;
+		let cached = grid_cache::get_or_build(submitted, &block_length, seed)?;
+		let grid = &cached.grid;
 
 		// let orig_dims = non_extended_dims(grid.dims()).ok_or(Error::InvalidDimension)?;
 		let dims = grid.dims();
+		// Apps without data in this block have no rows.
 		let Some(rows) = grid.app_rows(AppId(app_id), Some(dims))? else {
-			return Err(Error::AppRow);
+			return Ok(Vec::new());
 		};
 
 		let mut all_rows = vec![None; dims.height()];
@@ -118,10 +181,3 @@ pub trait HostedKate {
 		Ok(all_rows)
 	}
 }
-
-fn to_width_height(block_len: &BlockLength) -> (usize, usize) {
-	// even if we run on a u16 target this is fine
-	let width = block_len.cols.0.saturated_into();
-	let height = block_len.rows.0.saturated_into();
-	(width, height)
-}
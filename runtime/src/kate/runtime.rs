@@ -1,7 +1,7 @@
-use super::{native::hosted_kate, Error, GDataProof, GRow};
+use super::{native::hosted_kate, Error, GDataProof, GMultiProofs, GRow};
 use da_control::LOG_TARGET as DALOG_TARGET;
 
-use avail_core::AppExtrinsic;
+use avail_core::{AppExtrinsic, AppId};
 use frame_system::{limits::BlockLength, Config as SystemConfig};
 use kate::Seed;
 
@@ -41,3 +41,29 @@ pub fn proof<T: SystemConfig>(
 	let seed = random_seed::<T>();
 	hosted_kate::proof(app_extrinsics, block_len, seed, cells)
 }
+
+pub fn multiproof<T: SystemConfig>(
+	app_extrinsics: Vec<AppExtrinsic>,
+	block_len: BlockLength,
+	cells: Vec<(u32, u32)>,
+) -> Result<GMultiProofs, Error> {
+	let seed = random_seed::<T>();
+	hosted_kate::multiproof(app_extrinsics, block_len, seed, cells)
+}
+
+pub fn app_data<T: SystemConfig>(
+	app_extrinsics: Vec<AppExtrinsic>,
+	block_len: BlockLength,
+	app_id: AppId,
+) -> Result<Vec<(u32, GRow)>, Error> {
+	let seed = random_seed::<T>();
+	let all_rows = hosted_kate::app_data(app_extrinsics, block_len, seed, app_id.0)?;
+
+	// Keep only the rows which belong to `app_id`, along with their index in the grid.
+	all_rows
+		.into_iter()
+		.enumerate()
+		.filter_map(|(row_idx, row)| row.map(|row| (row_idx, row)))
+		.map(|(row_idx, row)| Ok((u32::try_from(row_idx)?, row)))
+		.collect()
+}
@@ -149,6 +149,7 @@ construct_runtime!(
 		Proxy: pallet_proxy = 40,
 		TxPause: pallet_tx_pause = 41,
 		TreasuryCommittee: pallet_collective::<Instance1> = 42,
+		Assets: pallet_assets = 43,
 	}
 );
 
@@ -193,6 +194,7 @@ mod benches {
 		[pallet_proxy, crate::Proxy]
 		[pallet_tx_pause, crate::TxPause]
 		[pallet_collective, crate::TreasuryCommittee]
+		[pallet_assets, crate::Assets]
 	);
 }
 
@@ -57,8 +57,7 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
-	da_control::CheckAppId<Runtime>,
+	da_control::ChargeAppTransactionPayment<Runtime>,
 );
 
 /// The payload being signed in transactions.
@@ -72,8 +71,15 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// Storage migrations executed on runtime upgrade.
+pub type Migrations = (
+	da_control::migrations::v1::MigrateToV1<Runtime>,
+	pallet_vector::migrations::v1::MigrateToV1<Runtime>,
+);
+
 /// ID type for named reserves.
 pub type ReserveIdentifier = [u8; 8];
 
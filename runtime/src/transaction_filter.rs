@@ -13,7 +13,8 @@ use pallet_vector::Call as VectorCall;
 use sp_core::H256;
 use sp_std::vec::Vec;
 
-/// Filters and extracts `data` from `call` if it is a `DataAvailability::submit_data` type.
+/// Filters and extracts `data` from `call` if it is a `DataAvailability::submit_data` or
+/// `DataAvailability::submit_data_with_options` type.
 impl HeaderExtensionDataFilter for Runtime {
 	fn filter(
 		failed_transactions: &[u32],
@@ -64,8 +65,12 @@ fn filter_da_call(
 	app_id: AppId,
 	tx_index: usize,
 ) -> Option<ExtractedTxData> {
-	let DACall::submit_data { data } = call else {
-		return None;
+	let (data, namespace) = match call {
+		DACall::submit_data { data } => (data, None),
+		DACall::submit_data_with_options {
+			data, namespace, ..
+		} => (data, *namespace),
+		_ => return None,
 	};
 
 	if data.is_empty() {
@@ -77,6 +82,7 @@ fn filter_da_call(
 		app_id,
 		tx_index,
 		data.as_slice().to_vec(),
+		namespace,
 	));
 
 	Some(ExtractedTxData {
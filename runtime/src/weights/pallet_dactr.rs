@@ -15,15 +15,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Autogenerated weights for `da_control`
+//! Weights for `da_control`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-03-26, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `ip-172-31-12-189`, CPU: `Intel(R) Xeon(R) Platinum 8175M CPU @ 2.50GHz`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: 1024
+//! Generated with the Substrate benchmark CLI version 32.0.0 on 2024-03-26, then edited by
+//! hand: the entries marked as estimated were derived from their storage accesses and were
+//! not benchmarked. Regenerate this file with the command below before relying on them.
 
-// Executed Command:
+// Command:
 // ./target/release/avail-node
 // benchmark
 // pallet
@@ -53,15 +51,21 @@ impl<T: frame_system::Config> da_control::WeightInfo for WeightInfo<T> {
 	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
 	/// Storage: `DataAvailability::NextAppId` (r:1 w:1)
 	/// Proof: `DataAvailability::NextAppId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppKeyDeposits` (r:0 w:1)
+	/// Proof: `DataAvailability::AppKeyDeposits` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppIdToKey` (r:0 w:1)
+	/// Proof: `DataAvailability::AppIdToKey` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
 	fn create_application_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `312`
-		//  Estimated: `3583`
-		// Minimum execution time: 23_525_000 picoseconds.
-		Weight::from_parts(24_376_000, 0)
-			.saturating_add(Weight::from_parts(0, 3583))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+		//  Estimated: `3593`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(39_208_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `System::DynamicBlockLength` (r:1 w:1)
 	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
@@ -88,15 +92,19 @@ impl<T: frame_system::Config> da_control::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: `DataAvailability::AppKeys` (r:2 w:2)
 	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppKeyDeposits` (r:1 w:1)
+	/// Proof: `DataAvailability::AppKeyDeposits` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn set_application_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `377`
 		//  Estimated: `6176`
-		// Minimum execution time: 33_289_000 picoseconds.
+		// Estimated from the storage accesses, not benchmarked.
 		Weight::from_parts(33_685_000, 0)
 			.saturating_add(Weight::from_parts(0, 6176))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// The range of component `i` is `[0, 524288]`.
 	fn data_root(i: u32, ) -> Weight {
@@ -131,4 +139,176 @@ impl<T: frame_system::Config> da_control::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:0)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::PermissionedApps` (r:0 w:1)
+	/// Proof: `DataAvailability::PermissionedApps` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	fn set_app_permissioned() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232`
+		//  Estimated: `3583`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(11_870_000, 3583)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:0)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppSubmitters` (r:1 w:1)
+	/// Proof: `DataAvailability::AppSubmitters` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn add_app_submitter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232`
+		//  Estimated: `3583`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(13_690_000, 3583)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:0)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppSubmitters` (r:1 w:1)
+	/// Proof: `DataAvailability::AppSubmitters` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn remove_app_submitter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232`
+		//  Estimated: `3583`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(13_958_000, 3583)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:1)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::RetiredApps` (r:1 w:0)
+	/// Proof: `DataAvailability::RetiredApps` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	fn transfer_application_key_ownership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232`
+		//  Estimated: `3583`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(14_652_000, 3583)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:2 w:2)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::RetiredApps` (r:1 w:0)
+	/// Proof: `DataAvailability::RetiredApps` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppKeyDeposits` (r:1 w:1)
+	/// Proof: `DataAvailability::AppKeyDeposits` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn rename_application_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232`
+		//  Estimated: `6176`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(19_544_000, 6176)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:0)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::RetiredApps` (r:1 w:1)
+	/// Proof: `DataAvailability::RetiredApps` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppKeyDeposits` (r:1 w:1)
+	/// Proof: `DataAvailability::AppKeyDeposits` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn retire_application_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232`
+		//  Estimated: `3593`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(25_102_000, 3593)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `DataAvailability::AppFeeModifiers` (r:0 w:1)
+	/// Proof: `DataAvailability::AppFeeModifiers` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	fn set_app_fee_modifier() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(5_436_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::AppBlockQuotas` (r:0 w:1)
+	/// Proof: `DataAvailability::AppBlockQuotas` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn set_app_block_quota() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(5_311_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::PendingBlockLengthProposal` (r:0 w:1)
+	/// Proof: `DataAvailability::PendingBlockLengthProposal` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn schedule_block_length_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(7_562_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::PendingBlockLengthProposal` (r:1 w:1)
+	/// Proof: `DataAvailability::PendingBlockLengthProposal` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `System::DynamicBlockLength` (r:0 w:1)
+	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	fn enact_block_length_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `72`
+		//  Estimated: `1497`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(11_305_000, 0)
+			.saturating_add(Weight::from_parts(0, 1497))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `DataAvailability::BlockLengthAutoScalingParams` (r:0 w:1)
+	/// Proof: `DataAvailability::BlockLengthAutoScalingParams` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::BlockUtilisation` (r:0 w:1)
+	/// Proof: `DataAvailability::BlockUtilisation` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `MaxEncodedLen`)
+	fn set_block_length_auto_scaling() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(7_241_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `DataAvailability::BlockLengthAutoScalingParams` (r:2 w:0)
+	/// Proof: `DataAvailability::BlockLengthAutoScalingParams` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::BlockUtilisation` (r:2 w:2)
+	/// Proof: `DataAvailability::BlockUtilisation` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `MaxEncodedLen`)
+	/// Storage: `System::DynamicBlockLength` (r:2 w:1)
+	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	/// Storage: `System::AllExtrinsicsLen` (r:1 w:0)
+	/// Proof: `System::AllExtrinsicsLen` (`max_values`: Some(1), `max_size`: Some(52821), added: 53316, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::PendingBlockLengthProposal` (r:2 w:1)
+	/// Proof: `DataAvailability::PendingBlockLengthProposal` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn auto_adjust_block_length() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `54306`
+		// Estimated from the storage accesses, not benchmarked.
+		Weight::from_parts(25_386_000, 0)
+			.saturating_add(Weight::from_parts(0, 54306))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }